@@ -0,0 +1,366 @@
+use std::cmp::Ordering;
+
+const BASE: u64 = 1_000_000_000;
+const BASE_DIGITS: usize = 9;
+
+/// Arbitrary-precision integer, integer arithmetic continues with it once 64 bits overflow.
+/// The magnitude is kept in base 10^9 limbs, least significant first,
+/// so printing and parsing decimals needs no conversion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BigInt {
+    negative: bool,
+    // No leading zero limbs, zero has no limbs at all and is never negative
+    limbs: Vec<u32>,
+}
+
+impl BigInt {
+    /// Largest result `pow` computes, bigger ones would take ages and exhaust memory.
+    pub const MAX_POW_BITS: u32 = 1 << 16;
+
+    fn new(negative: bool, limbs: Vec<u32>) -> Self {
+        let limbs = trim(limbs);
+        let negative = negative && !limbs.is_empty();
+
+        BigInt { negative, limbs }
+    }
+
+    /// Parses digits in the given radix, with an optional leading `-`.
+    pub fn parse_radix(digits: &str, radix: u32) -> Option<Self> {
+        let (negative, digits) = match digits.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, digits),
+        };
+        if digits.is_empty() {
+            return None;
+        }
+
+        let mut limbs = vec![];
+        for c in digits.chars() {
+            limbs = mul_small(&limbs, radix, c.to_digit(radix)?);
+        }
+
+        Some(BigInt::new(negative, limbs))
+    }
+
+    /// Integer part of a float, `None` for infinities and NaN.
+    pub fn from_f64(x: f64) -> Option<Self> {
+        if !x.is_finite() {
+            return None;
+        }
+
+        let x = x.trunc();
+        if x.abs() < i64::MAX as f64 {
+            return Some(BigInt::from(x as i64));
+        }
+
+        // Anything this large is a whole number: mantissa * 2^exponent
+        let bits = x.to_bits();
+        let exponent = ((bits >> 52) & 0x7ff) as u32 - 1075;
+        let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        let power = BigInt::from(2)
+            .pow(exponent)
+            .expect("A float exponent is far below the limit");
+        let magnitude = &BigInt::from(mantissa as i64) * &power;
+
+        Some(if x < 0.0 { -&magnitude } else { magnitude })
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        if self.limbs.len() > 3 {
+            return None;
+        }
+
+        let magnitude = self
+            .limbs
+            .iter()
+            .rev()
+            .fold(0i128, |value, &limb| value * BASE as i128 + limb as i128);
+
+        i64::try_from(if self.negative { -magnitude } else { magnitude }).ok()
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        let magnitude = self
+            .limbs
+            .iter()
+            .rev()
+            .fold(0.0, |value, &limb| value * BASE as f64 + limb as f64);
+
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// Quotient truncated towards zero and the remainder with the sign of `self`,
+    /// `None` when dividing by zero.
+    pub fn div_rem(&self, divisor: &BigInt) -> Option<(BigInt, BigInt)> {
+        if divisor.is_zero() {
+            return None;
+        }
+
+        let (quotient, remainder) = div_rem_magnitude(&self.limbs, &divisor.limbs);
+
+        Some((
+            BigInt::new(self.negative != divisor.negative, quotient),
+            BigInt::new(self.negative, remainder),
+        ))
+    }
+
+    /// `self ** exponent`, `None` when the result would have more than
+    /// [`BigInt::MAX_POW_BITS`] bits.
+    pub fn pow(&self, mut exponent: u32) -> Option<BigInt> {
+        if self.log2() * exponent as f64 > BigInt::MAX_POW_BITS as f64 {
+            return None;
+        }
+
+        let mut result = BigInt::from(1);
+        let mut base = self.clone();
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Approximate `log2(|self|)`, 0 for zero.
+    fn log2(&self) -> f64 {
+        match self.limbs.split_last() {
+            None => 0.0,
+            Some((top, rest)) => rest.len() as f64 * (BASE as f64).log2() + (*top as f64).log2(),
+        }
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        let mut magnitude = value.unsigned_abs();
+        let mut limbs = vec![];
+        while magnitude > 0 {
+            limbs.push((magnitude % BASE) as u32);
+            magnitude /= BASE;
+        }
+
+        BigInt::new(value < 0, limbs)
+    }
+}
+
+impl std::ops::Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.limbs.clone())
+    }
+}
+
+impl std::ops::Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: &BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, add_magnitude(&self.limbs, &other.limbs));
+        }
+
+        // Opposite signs, the larger magnitude decides the sign
+        return match cmp_magnitude(&self.limbs, &other.limbs) {
+            Ordering::Less => BigInt::new(other.negative, sub_magnitude(&other.limbs, &self.limbs)),
+            _ => BigInt::new(self.negative, sub_magnitude(&self.limbs, &other.limbs)),
+        };
+    }
+}
+
+impl std::ops::Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: &BigInt) -> BigInt {
+        self + &-other
+    }
+}
+
+impl std::ops::Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: &BigInt) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            mul_magnitude(&self.limbs, &other.limbs),
+        )
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => cmp_magnitude(&self.limbs, &other.limbs),
+            (true, true) => cmp_magnitude(&other.limbs, &self.limbs),
+        };
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl std::fmt::Display for BigInt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((most_significant, rest)) = self.limbs.split_last() else {
+            return write!(f, "0");
+        };
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "{most_significant}")?;
+        for limb in rest.iter().rev() {
+            write!(f, "{limb:0width$}", width = BASE_DIGITS)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn trim(mut limbs: Vec<u32>) -> Vec<u32> {
+    while limbs.last() == Some(&0) {
+        limbs.pop();
+    }
+
+    limbs
+}
+
+fn cmp_magnitude(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0;
+    for i in 0..a.len().max(b.len()) {
+        let sum = *a.get(i).unwrap_or(&0) as u64 + *b.get(i).unwrap_or(&0) as u64 + carry;
+        result.push((sum % BASE) as u32);
+        carry = sum / BASE;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+
+    result
+}
+
+/// `a - b`, where `a` is at least `b`.
+fn sub_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = 0;
+    for (i, &limb) in a.iter().enumerate() {
+        let mut difference = limb as i64 - *b.get(i).unwrap_or(&0) as i64 - borrow;
+        borrow = 0;
+        if difference < 0 {
+            difference += BASE as i64;
+            borrow = 1;
+        }
+        result.push(difference as u32);
+    }
+
+    trim(result)
+}
+
+fn mul_magnitude(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+
+    let mut result = vec![0u64; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            let product = result[i + j] + x as u64 * y as u64 + carry;
+            result[i + j] = product % BASE;
+            carry = product / BASE;
+        }
+        result[i + b.len()] += carry;
+    }
+
+    trim(result.into_iter().map(|limb| limb as u32).collect())
+}
+
+/// `a * factor + addend` for single limb values.
+fn mul_small(a: &[u32], factor: u32, addend: u32) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len() + 1);
+    let mut carry = addend as u64;
+    for &limb in a {
+        let product = limb as u64 * factor as u64 + carry;
+        result.push((product % BASE) as u32);
+        carry = product / BASE;
+    }
+    if carry > 0 {
+        result.push(carry as u32);
+    }
+
+    trim(result)
+}
+
+/// Schoolbook long division, `b` must not be zero.
+fn div_rem_magnitude(a: &[u32], b: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if cmp_magnitude(a, b) == Ordering::Less {
+        return (vec![], a.to_vec());
+    }
+
+    let mut quotient = vec![0; a.len()];
+
+    if let [divisor] = b {
+        let mut remainder = 0;
+        for i in (0..a.len()).rev() {
+            let current = remainder * BASE + a[i] as u64;
+            quotient[i] = (current / *divisor as u64) as u32;
+            remainder = current % *divisor as u64;
+        }
+
+        return (trim(quotient), trim(vec![remainder as u32]));
+    }
+
+    let n = b.len();
+    let limb = |limbs: &[u32], i: usize| *limbs.get(i).unwrap_or(&0) as u128;
+    // The two leading limbs of the divisor give an estimate at most 2 above the real digit
+    let divisor_top = limb(b, n - 1) * BASE as u128 + limb(b, n - 2);
+
+    let mut remainder = vec![];
+    for i in (0..a.len()).rev() {
+        remainder.insert(0, a[i]);
+        remainder = trim(remainder);
+        if cmp_magnitude(&remainder, b) == Ordering::Less {
+            continue;
+        }
+
+        let remainder_top = (limb(&remainder, n) * BASE as u128 + limb(&remainder, n - 1))
+            * BASE as u128
+            + limb(&remainder, n - 2);
+        let mut digit = (remainder_top / divisor_top).min(BASE as u128 - 1) as u32;
+        let mut product = mul_small(b, digit, 0);
+        while cmp_magnitude(&product, &remainder) == Ordering::Greater {
+            digit -= 1;
+            product = mul_small(b, digit, 0);
+        }
+
+        remainder = sub_magnitude(&remainder, &product);
+        quotient[i] = digit;
+    }
+
+    (trim(quotient), remainder)
+}
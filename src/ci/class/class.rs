@@ -3,9 +3,9 @@ use std::{
     rc::{Rc, Weak},
 };
 
-use crate::ci::Environment;
+use crate::ci::{Environment, Evaluation, Function};
 
-pub(crate) const CLASS_STR: &'static str = "class";
+pub(crate) const CLASS_STR: &str = "class";
 
 #[derive(Clone)]
 pub struct Class<'de> {
@@ -13,4 +13,43 @@ pub struct Class<'de> {
     pub super_class: Option<Weak<Class<'de>>>,
     // TODO: Should be weak when(if) persistent environment will be introduced
     pub class_environment: Rc<RefCell<Environment<'de>>>,
+    /// Fields declared `final`, set once per instance
+    pub final_fields: Vec<&'de str>,
+}
+
+impl<'de> Class<'de> {
+    pub fn super_class(&self) -> Option<Rc<Class<'de>>> {
+        self.super_class
+            .as_ref()
+            .and_then(|super_class| super_class.upgrade())
+    }
+
+    pub fn find_method(&self, name: &str) -> Option<Rc<Function<'de>>> {
+        if let Some(Evaluation::Fn(func)) = self.class_environment.borrow().get(name) {
+            return Some(func);
+        }
+
+        self.super_class()
+            .and_then(|super_class| super_class.find_method(name))
+    }
+
+    pub fn is_final_field(&self, name: &str) -> bool {
+        if self.final_fields.contains(&name) {
+            return true;
+        }
+
+        self.super_class()
+            .map(|super_class| super_class.is_final_field(name))
+            .unwrap_or(false)
+    }
+
+    pub fn is_subclass_of(&self, class: &Class<'de>) -> bool {
+        if std::ptr::eq(self, class) {
+            return true;
+        }
+
+        self.super_class()
+            .map(|super_class| super_class.is_subclass_of(class))
+            .unwrap_or(false)
+    }
 }
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     cell::RefCell,
     collections::HashMap,
     rc::{Rc, Weak},
@@ -8,11 +9,11 @@ use crate::{ci::Evaluation, get_environment_value_typed};
 
 use super::{method::INIT_STR, Class, ClassMethod};
 
-pub(crate) const THIS_STR: &'static str = "this";
+pub(crate) const THIS_STR: &str = "this";
 
 pub struct ClassInstance<'de> {
     class: Rc<Class<'de>>,
-    fields: HashMap<&'de str, Evaluation<'de>>,
+    fields: HashMap<Cow<'de, str>, Evaluation<'de>>,
 
     weak_self: Weak<RefCell<ClassInstance<'de>>>,
 }
@@ -33,18 +34,28 @@ impl<'de> ClassInstance<'de> {
         class_instance
     }
 
-    pub fn class(&self) -> &Class<'de> {
+    pub fn class(&self) -> &Rc<Class<'de>> {
         &self.class
     }
 
-    pub fn get_field(&self, name: &'de str) -> Result<Evaluation<'de>, anyhow::Error> {
+    pub fn fields(&self) -> impl Iterator<Item = (&str, &Evaluation<'de>)> {
+        self.fields
+            .iter()
+            .map(|(name, value)| (name.as_ref(), value))
+    }
+
+    pub fn has_field(&self, name: &str) -> bool {
+        self.fields.contains_key(name)
+    }
+
+    pub fn get_field(&self, name: &str) -> Result<Evaluation<'de>, anyhow::Error> {
         self.get_filed_for_class(self.class.clone(), name)
     }
 
     pub fn get_filed_for_class(
         &self,
         class: Rc<Class<'de>>,
-        name: &'de str,
+        name: &str,
     ) -> Result<Evaluation<'de>, anyhow::Error> {
         let class_environment = class.class_environment.clone();
         let class_environment_ = class_environment.borrow();
@@ -74,7 +85,7 @@ impl<'de> ClassInstance<'de> {
         });
     }
 
-    fn get_field_super(&self, name: &'de str) -> Result<Evaluation<'de>, anyhow::Error> {
+    fn get_field_super(&self, name: &str) -> Result<Evaluation<'de>, anyhow::Error> {
         let mut super_class = self.class.super_class.clone();
 
         // As we do not have static methods, there should always be a `class_instance` for the method call
@@ -107,8 +118,19 @@ impl<'de> ClassInstance<'de> {
         anyhow::bail! {"Undefined property '{}'.", name}
     }
 
-    pub fn set_field(&mut self, name: &'de str, value: Evaluation<'de>) {
+    pub fn set_field(
+        &mut self,
+        name: impl Into<Cow<'de, str>>,
+        value: Evaluation<'de>,
+    ) -> Result<(), anyhow::Error> {
+        let name = name.into();
+        if self.fields.contains_key(&name) && self.class.is_final_field(&name) {
+            anyhow::bail! {"Can't assign to final field '{}'.", name}
+        }
+
         let _ = self.fields.insert(name, value);
+
+        return Ok(());
     }
 
     pub fn get_constructor(&self) -> Result<Evaluation<'de>, anyhow::Error> {
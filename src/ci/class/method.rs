@@ -4,7 +4,7 @@ use crate::ci::{Environment, Function};
 
 use super::ClassInstance;
 
-pub(crate) const INIT_STR: &'static str = "init";
+pub(crate) const INIT_STR: &str = "init";
 
 #[derive(Clone)]
 pub struct ClassMethod<'de> {
@@ -1,6 +1,10 @@
-use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    rc::Rc,
+};
 
-use super::{function::native::native_functions, interpreter};
+use super::{function::native::native_functions, interpreter, RuntimeOptions};
 
 #[macro_export]
 macro_rules! get_environment_value_typed {
@@ -19,32 +23,24 @@ macro_rules! get_environment_value_typed {
     }};
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Environment<'de> {
     values: HashMap<&'de str, interpreter::Evaluation<'de>>,
     functions: HashMap<&'de str, interpreter::Evaluation<'de>>,
+    /// Names in `values` bound with `const`
+    constants: HashSet<&'de str>,
 
     enclosing: Option<Rc<RefCell<Environment<'de>>>>,
 
     is_global_scope: bool,
 }
 
-impl<'a, 'de> Default for Environment<'de> {
-    fn default() -> Self {
-        Self {
-            values: Default::default(),
-            functions: Default::default(),
-            enclosing: None,
-            is_global_scope: false,
-        }
-    }
-}
-
 impl<'de> Environment<'de> {
-    pub(crate) fn root() -> Self {
+    pub(crate) fn root(options: &RuntimeOptions) -> Self {
         Environment {
             values: Default::default(),
-            functions: native_functions(),
+            functions: native_functions(options),
+            constants: Default::default(),
             enclosing: None,
             is_global_scope: true,
         }
@@ -54,6 +50,7 @@ impl<'de> Environment<'de> {
         Environment {
             values: Default::default(),
             functions: Default::default(),
+            constants: Default::default(),
             enclosing: Some(enclosing),
             is_global_scope: false,
         }
@@ -66,20 +63,37 @@ impl<'de> Environment<'de> {
     pub(crate) fn define(&mut self, name: &'de str, value: interpreter::Evaluation<'de>) {
         // TODO: check what if we are defining var that is already defined
         let _ = self.values.insert(name, value);
+        let _ = self.constants.remove(name);
+    }
+
+    pub(crate) fn define_constant(&mut self, name: &'de str, value: interpreter::Evaluation<'de>) {
+        let _ = self.values.insert(name, value);
+        let _ = self.constants.insert(name);
     }
 
-    pub(crate) fn set(&mut self, name: &str, value: interpreter::Evaluation<'de>) -> bool {
+    pub(crate) fn set(
+        &mut self,
+        name: &str,
+        value: interpreter::Evaluation<'de>,
+    ) -> anyhow::Result<bool> {
         if let Some(var) = self.values.get_mut(name) {
+            if self.constants.contains(name) {
+                anyhow::bail!("Can't assign to constant '{name}'.");
+            }
+
             *var = value;
 
-            return true;
+            return Ok(true);
+        } else if self.functions.contains_key(name) {
+            // Natives such as `PI` or `clock` are constants, they can only be shadowed
+            anyhow::bail!("Can't assign to constant '{name}'.");
         } else {
             if let Some(enclosing) = self.enclosing.clone() {
-                if enclosing.borrow_mut().set(name, value.clone()) {
-                    return true;
+                if enclosing.borrow_mut().set(name, value.clone())? {
+                    return Ok(true);
                 }
             }
-            return false;
+            return Ok(false);
             // TODO: Should it be an error if we set a value that is not in the environment ?
         }
     }
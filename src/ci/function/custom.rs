@@ -1,40 +1,105 @@
 use std::{cell::RefCell, rc::Rc};
 
 use crate::{
-    ci::{interpreter::Interruption, Environment, Evaluation, Interpret, Node},
+    ci::{
+        grammar::declaration::Parameter, interpreter::Interruption, Environment, Evaluation,
+        Interpret, Node,
+    },
     Token,
 };
 
-use super::Callable;
+use super::{check_arity, Callable};
 
 #[derive(Clone)]
 pub struct CustomFunction<'de> {
     pub name: &'de str,
-    pub arity: usize,
-    pub parameters: Vec<Token<'de>>,
+    pub parameters: Vec<Parameter<'de>>,
     // TODO: Should be weak when(if) persistent environment will be introduced
     pub captured_environment: Rc<RefCell<Environment<'de>>>,
     pub body: Rc<Node<'de>>,
 }
 
 impl<'de> CustomFunction<'de> {
+    /// The parameters before the first one with a default or the rest one.
+    pub fn min_arity(&self) -> usize {
+        return self
+            .parameters
+            .iter()
+            .take_while(|parameter| parameter.default.is_none() && !parameter.rest)
+            .count();
+    }
+
+    pub fn max_arity(&self) -> Option<usize> {
+        return match self.parameters.last() {
+            Some(parameter) if parameter.rest => None,
+            _ => Some(self.parameters.len()),
+        };
+    }
+
+    /// Binds the positional arguments in order, then the named ones, and evaluates the defaults
+    /// of whatever is left, extra positional arguments go to the rest parameter as a list.
     pub fn call_with_environment(
         &self,
         environment: Rc<RefCell<Environment<'de>>>,
-        arguments: impl IntoIterator<Item = Evaluation<'de>>,
+        arguments: Vec<Evaluation<'de>>,
+        named_arguments: Vec<(Token<'de>, Evaluation<'de>)>,
     ) -> Result<Evaluation<'de>, Interruption<'de>> {
+        for (i, (name, _)) in named_arguments.iter().enumerate() {
+            let position = self
+                .parameters
+                .iter()
+                .position(|parameter| !parameter.rest && parameter.name.lexeme == name.lexeme);
+
+            let Some(position) = position else {
+                return Err(Interruption::Error(anyhow::anyhow! {
+                    "{}() has no parameter named '{}'.", self.name, name.lexeme
+                }));
+            };
+            if position < arguments.len()
+                || named_arguments[..i]
+                    .iter()
+                    .any(|(other, _)| other.lexeme == name.lexeme)
+            {
+                return Err(Interruption::Error(anyhow::anyhow! {
+                    "Parameter '{}' of {}() got more than one value.", name.lexeme, self.name
+                }));
+            }
+        }
+
+        check_arity(
+            self.min_arity(),
+            self.max_arity(),
+            arguments.len() + named_arguments.len(),
+        )
+        .map_err(Interruption::Error)?;
+
         // Create a new instance to easily set arguments into the environment
         let captured_environment = Rc::new(RefCell::new(Environment::new(environment)));
 
-        // TODO: check len(parameters) != len(arguments) ?
-        self.parameters
-            .iter()
-            .zip(arguments)
-            .for_each(|(parameter, argument)| {
-                captured_environment
-                    .borrow_mut()
-                    .define(parameter.lexeme, argument);
-            });
+        let mut arguments = arguments.into_iter();
+        let mut named_arguments = named_arguments;
+        for parameter in self.parameters.iter() {
+            let name = parameter.name.lexeme;
+            let named = named_arguments
+                .iter()
+                .position(|(other, _)| other.lexeme == name)
+                .map(|i| named_arguments.swap_remove(i).1);
+
+            let argument = if parameter.rest {
+                Evaluation::list(arguments.by_ref().collect())
+            } else if let Some(argument) = arguments.next().or(named) {
+                argument
+            } else if let Some(default) = &parameter.default {
+                // Earlier parameters are already defined, a default can use them
+                default.interpret(captured_environment.clone())?
+            } else {
+                return Err(Interruption::Error(anyhow::anyhow! {
+                    "Missing argument for parameter '{}' of {}().", name, self.name
+                }));
+            };
+
+            captured_environment.borrow_mut().define(name, argument);
+        }
 
         match self.body.interpret(captured_environment) {
             Err(Interruption::Return(evaluation)) => Ok(evaluation),
@@ -48,6 +113,10 @@ impl<'de> Callable<'de> for CustomFunction<'de> {
         &self,
         arguments: impl IntoIterator<Item = Evaluation<'de>>,
     ) -> Result<Evaluation<'de>, Interruption<'de>> {
-        self.call_with_environment(self.captured_environment.clone(), arguments)
+        self.call_with_environment(
+            self.captured_environment.clone(),
+            arguments.into_iter().collect(),
+            vec![],
+        )
     }
 }
@@ -19,12 +19,34 @@ impl<'de> Function<'de> {
         }
     }
 
-    pub fn arity(&self) -> usize {
+    /// Fewest positional arguments a call needs.
+    pub fn min_arity(&self) -> usize {
         match self {
-            Function::CustomFunction(callable) => callable.arity,
+            Function::CustomFunction(callable) => callable.min_arity(),
             Function::NativeFunction(callable) => callable.arity,
         }
     }
+
+    /// Most positional arguments a call takes, `None` when there is no limit.
+    pub fn max_arity(&self) -> Option<usize> {
+        match self {
+            Function::CustomFunction(callable) => callable.max_arity(),
+            Function::NativeFunction(callable) => callable.max_arity,
+        }
+    }
+}
+
+/// Fails unless `count` arguments fit in `min..=max`, a missing `max` has no upper bound.
+pub(crate) fn check_arity(min: usize, max: Option<usize>, count: usize) -> anyhow::Result<()> {
+    let expected = match max {
+        Some(max) if (min..=max).contains(&count) => return Ok(()),
+        None if count >= min => return Ok(()),
+        Some(max) if min == max => format!("{min}"),
+        Some(max) => format!("{min} to {max}"),
+        None => format!("at least {min}"),
+    };
+
+    anyhow::bail!("Expected {expected} arguments but got {count}.")
 }
 
 pub trait Callable<'de> {
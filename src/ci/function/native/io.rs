@@ -0,0 +1,122 @@
+use std::{
+    fs,
+    io::{self, BufRead, Write},
+};
+
+use crate::ci::{interpreter::Interruption, Evaluation, RuntimeOptions};
+
+use super::{expect_string, native};
+
+fn io_error<'de>(function: &str, path: &str, error: io::Error) -> Interruption<'de> {
+    Interruption::Error(anyhow::anyhow! {"{function}() failed for '{path}': {error}"})
+}
+
+fn read_line<'de>(function: &str) -> Result<Evaluation<'de>, Interruption<'de>> {
+    let _ = io::stdout().flush();
+
+    let mut line = String::new();
+    match io::stdin().lock().read_line(&mut line) {
+        // End of input
+        Ok(0) => Ok(Evaluation::Nil),
+        Ok(_) => {
+            let line = line.strip_suffix('\n').unwrap_or(&line);
+            let line = line.strip_suffix('\r').unwrap_or(line);
+
+            Ok(Evaluation::String(line.to_string()))
+        }
+        Err(error) => Err(io_error(function, "<stdin>", error)),
+    }
+}
+
+/// Filesystem natives are always defined, but fail at call time when the run is sandboxed.
+fn filesystem<'de>(
+    name: &'static str,
+    arity: usize,
+    options: &RuntimeOptions,
+    body: impl Fn(Vec<Evaluation<'de>>) -> Result<Evaluation<'de>, Interruption<'de>> + 'de,
+) -> (&'de str, Evaluation<'de>) {
+    let disabled = options.no_filesystem;
+
+    native(name, arity, move |args| {
+        if disabled {
+            return Err(Interruption::Error(anyhow::anyhow! {
+                "{name}() is not allowed: filesystem access is disabled."
+            }));
+        }
+
+        body(args)
+    })
+}
+
+pub(super) fn functions<'de>(options: &RuntimeOptions) -> Vec<(&'de str, Evaluation<'de>)> {
+    vec![
+        native("input", 1, |args| {
+            print!("{}", args[0]);
+
+            read_line("input")
+        }),
+        native("readLine", 0, |_| read_line("readLine")),
+        native("eprint", 1, |args| {
+            eprintln!("{}", args[0]);
+
+            Ok(Evaluation::Nil)
+        }),
+        filesystem("readFile", 1, options, |args| {
+            let path = expect_string("readFile", &args[0])?;
+
+            fs::read_to_string(path)
+                .map(Evaluation::String)
+                .map_err(|error| io_error("readFile", path, error))
+        }),
+        filesystem("writeFile", 2, options, |args| {
+            let path = expect_string("writeFile", &args[0])?;
+            let content = expect_string("writeFile", &args[1])?;
+
+            fs::write(path, content)
+                .map(|_| Evaluation::Nil)
+                .map_err(|error| io_error("writeFile", path, error))
+        }),
+        filesystem("appendFile", 2, options, |args| {
+            let path = expect_string("appendFile", &args[0])?;
+            let content = expect_string("appendFile", &args[1])?;
+
+            fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .and_then(|mut file| file.write_all(content.as_bytes()))
+                .map(|_| Evaluation::Nil)
+                .map_err(|error| io_error("appendFile", path, error))
+        }),
+        filesystem("removeFile", 1, options, |args| {
+            let path = expect_string("removeFile", &args[0])?;
+
+            fs::remove_file(path)
+                .map(|_| Evaluation::Nil)
+                .map_err(|error| io_error("removeFile", path, error))
+        }),
+        filesystem("fileExists", 1, options, |args| {
+            let path = expect_string("fileExists", &args[0])?;
+
+            Ok(Evaluation::Boolean(fs::metadata(path).is_ok()))
+        }),
+        filesystem("listDir", 1, options, |args| {
+            let path = expect_string("listDir", &args[0])?;
+
+            let mut names = fs::read_dir(path)
+                .and_then(|entries| {
+                    entries
+                        .map(|entry| {
+                            entry.map(|entry| entry.file_name().to_string_lossy().into_owned())
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .map_err(|error| io_error("listDir", path, error))?;
+            names.sort();
+
+            Ok(Evaluation::list(
+                names.into_iter().map(Evaluation::String).collect(),
+            ))
+        }),
+    ]
+}
@@ -0,0 +1,511 @@
+use std::rc::Rc;
+
+use crate::{
+    ci::{interpreter::Interruption, Evaluation},
+    BigInt,
+};
+
+use super::{expect_integer, expect_string, native, optional, type_error};
+
+const MAX_DEPTH: usize = 512;
+/// Largest `jsonStringify` indent, like `JSON.stringify`.
+const MAX_INDENT: i64 = 10;
+
+struct JsonParser {
+    chars: Vec<char>,
+    position: usize,
+    depth: usize,
+}
+
+impl JsonParser {
+    fn new(source: &str) -> Self {
+        Self {
+            chars: source.chars().collect(),
+            position: 0,
+            depth: 0,
+        }
+    }
+
+    fn parse<'de>(mut self) -> Result<Evaluation<'de>, String> {
+        let value = self.value()?;
+
+        self.skip_whitespace();
+        if let Some(c) = self.peek() {
+            return Err(self.error(&format!("Unexpected character '{c}' after JSON value")));
+        }
+
+        Ok(value)
+    }
+
+    /// Builds the error message pointing at the current position, lines and columns start at 1.
+    fn error(&self, message: &str) -> String {
+        let consumed = self.chars[..self.position.min(self.chars.len())]
+            .iter()
+            .collect::<String>();
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed
+            .rsplit('\n')
+            .next()
+            .map(|last_line| last_line.chars().count())
+            .unwrap_or(0)
+            + 1;
+
+        format!("{message} at line {line}, column {column}.")
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn advance(&mut self) -> Option<char> {
+        let c = self.peek();
+        if c.is_some() {
+            self.position += 1;
+        }
+        c
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), String> {
+        match self.peek() {
+            Some(c) if c == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            Some(c) => Err(self.error(&format!("Expected '{expected}' but found '{c}'"))),
+            None => Err(self.error(&format!("Expected '{expected}' but found end of input"))),
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn value<'de>(&mut self) -> Result<Evaluation<'de>, String> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('{') => self.nested(Self::object),
+            Some('[') => self.nested(Self::array),
+            Some('"') => self.string().map(Evaluation::String),
+            Some('t') => self.literal("true", Evaluation::Boolean(true)),
+            Some('f') => self.literal("false", Evaluation::Boolean(false)),
+            Some('n') => self.literal("null", Evaluation::Nil),
+            Some('-' | '0'..='9') => self.number(),
+            Some(c) => Err(self.error(&format!("Unexpected character '{c}'"))),
+            None => Err(self.error("Unexpected end of input")),
+        }
+    }
+
+    fn nested<'de>(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Evaluation<'de>, String>,
+    ) -> Result<Evaluation<'de>, String> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error("Nesting is too deep"));
+        }
+
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+
+        result
+    }
+
+    fn object<'de>(&mut self) -> Result<Evaluation<'de>, String> {
+        self.expect('{')?;
+
+        let mut entries: Vec<(String, Evaluation<'de>)> = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Evaluation::map(entries));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("Expected string key"));
+            }
+            let key = self.string()?;
+
+            self.skip_whitespace();
+            self.expect(':')?;
+            let value = self.value()?;
+
+            // Later duplicates win, as in most JSON implementations
+            match entries.iter_mut().find(|(name, _)| *name == key) {
+                Some(entry) => entry.1 = value,
+                None => entries.push((key, value)),
+            }
+
+            self.skip_whitespace();
+            match self.advance() {
+                Some(',') => continue,
+                Some('}') => return Ok(Evaluation::map(entries)),
+                _ => {
+                    self.position = self.position.saturating_sub(1);
+                    return Err(self.error("Expected ',' or '}' in object"));
+                }
+            }
+        }
+    }
+
+    fn array<'de>(&mut self) -> Result<Evaluation<'de>, String> {
+        self.expect('[')?;
+
+        let mut items = vec![];
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Evaluation::list(items));
+        }
+
+        loop {
+            items.push(self.value()?);
+
+            self.skip_whitespace();
+            match self.advance() {
+                Some(',') => continue,
+                Some(']') => return Ok(Evaluation::list(items)),
+                _ => {
+                    self.position = self.position.saturating_sub(1);
+                    return Err(self.error("Expected ',' or ']' in array"));
+                }
+            }
+        }
+    }
+
+    fn literal<'de>(
+        &mut self,
+        text: &str,
+        value: Evaluation<'de>,
+    ) -> Result<Evaluation<'de>, String> {
+        for expected in text.chars() {
+            if self.peek() != Some(expected) {
+                return Err(self.error(&format!("Invalid literal, expected '{text}'")));
+            }
+            self.position += 1;
+        }
+
+        Ok(value)
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.position;
+        while matches!(self.peek(), Some('0'..='9')) {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    fn number<'de>(&mut self) -> Result<Evaluation<'de>, String> {
+        let start = self.position;
+
+        if self.peek() == Some('-') {
+            self.position += 1;
+        }
+
+        match self.peek() {
+            Some('0') => self.position += 1,
+            Some('1'..='9') => {
+                let _ = self.digits();
+            }
+            _ => return Err(self.error("Invalid number, expected a digit")),
+        }
+
+        if self.peek() == Some('.') {
+            self.position += 1;
+            if self.digits() == 0 {
+                return Err(self.error("Invalid number, expected a digit after '.'"));
+            }
+        }
+
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.position += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.position += 1;
+            }
+            if self.digits() == 0 {
+                return Err(self.error("Invalid number, expected a digit in the exponent"));
+            }
+        }
+
+        let literal = self.chars[start..self.position].iter().collect::<String>();
+        if !literal.contains(['.', 'e', 'E']) {
+            if let Some(integer) = BigInt::parse_radix(&literal, 10) {
+                return Ok(Evaluation::integer(integer));
+            }
+        }
+
+        literal
+            .parse::<f64>()
+            .map(Evaluation::Number)
+            .map_err(|_| self.error("Invalid number"))
+    }
+
+    fn hex4(&mut self) -> Result<u32, String> {
+        let mut code = 0;
+        for _ in 0..4 {
+            match self.peek().and_then(|c| c.to_digit(16)) {
+                Some(digit) => {
+                    code = code * 16 + digit;
+                    self.position += 1;
+                }
+                None => return Err(self.error("Invalid unicode escape")),
+            }
+        }
+
+        Ok(code)
+    }
+
+    fn string(&mut self) -> Result<String, String> {
+        self.expect('"')?;
+
+        let mut s = String::new();
+        loop {
+            match self.peek() {
+                None => return Err(self.error("Unterminated string")),
+                Some('"') => {
+                    self.position += 1;
+                    return Ok(s);
+                }
+                Some('\\') => {
+                    self.position += 1;
+                    match self.advance() {
+                        Some('"') => s.push('"'),
+                        Some('\\') => s.push('\\'),
+                        Some('/') => s.push('/'),
+                        Some('b') => s.push('\u{8}'),
+                        Some('f') => s.push('\u{c}'),
+                        Some('n') => s.push('\n'),
+                        Some('r') => s.push('\r'),
+                        Some('t') => s.push('\t'),
+                        Some('u') => s.push(self.unicode_escape()?),
+                        _ => {
+                            self.position -= 1;
+                            return Err(self.error("Invalid escape sequence"));
+                        }
+                    }
+                }
+                Some(c) if (c as u32) < 0x20 => {
+                    return Err(self.error("Control character in string"));
+                }
+                Some(c) => {
+                    self.position += 1;
+                    s.push(c);
+                }
+            }
+        }
+    }
+
+    fn unicode_escape(&mut self) -> Result<char, String> {
+        let high = self.hex4()?;
+
+        let code = if (0xD800..0xDC00).contains(&high) {
+            // Surrogate pair, the low half has to follow right away
+            if self.advance() != Some('\\') || self.advance() != Some('u') {
+                return Err(self.error("Expected low surrogate"));
+            }
+            let low = self.hex4()?;
+            if !(0xDC00..0xE000).contains(&low) {
+                return Err(self.error("Invalid low surrogate"));
+            }
+
+            0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+        } else {
+            high
+        };
+
+        char::from_u32(code).ok_or_else(|| self.error("Invalid unicode escape"))
+    }
+}
+
+struct JsonWriter {
+    indent: usize,
+    output: String,
+    // Containers being written, to reject cyclic structures
+    stack: Vec<*const ()>,
+}
+
+impl JsonWriter {
+    fn new(indent: usize) -> Self {
+        Self {
+            indent,
+            output: String::new(),
+            stack: vec![],
+        }
+    }
+
+    fn write(mut self, value: &Evaluation<'_>) -> Result<String, String> {
+        self.value(value)?;
+
+        Ok(self.output)
+    }
+
+    fn newline(&mut self) -> Result<(), String> {
+        if self.indent > 0 {
+            self.output.push('\n');
+            let width = self
+                .indent
+                .checked_mul(self.stack.len())
+                .ok_or("indentation is too deep")?;
+            self.output.push_str(&" ".repeat(width));
+        }
+
+        Ok(())
+    }
+
+    fn enter(&mut self, pointer: *const ()) -> Result<(), String> {
+        if self.stack.contains(&pointer) {
+            return Err("cyclic structure can't be serialized".to_string());
+        }
+
+        self.stack.push(pointer);
+        Ok(())
+    }
+
+    fn value(&mut self, value: &Evaluation<'_>) -> Result<(), String> {
+        match value {
+            Evaluation::Nil | Evaluation::None => self.output.push_str("null"),
+            Evaluation::Boolean(b) => self.output.push_str(&b.to_string()),
+            Evaluation::Number(n) if n.is_finite() => self.output.push_str(&n.to_string()),
+            Evaluation::Integer(n) => self.output.push_str(&n.to_string()),
+            Evaluation::BigInteger(n) => self.output.push_str(&n.to_string()),
+            Evaluation::Number(n) => return Err(format!("{n} can't be serialized")),
+            Evaluation::String(s) => self.string(s),
+            Evaluation::List(l) => {
+                self.enter(Rc::as_ptr(l) as *const ())?;
+                let items = l.borrow().clone();
+                self.sequence('[', ']', &items, |writer, item| writer.value(item))?;
+                self.stack.pop();
+            }
+            Evaluation::Map(m) => {
+                self.enter(Rc::as_ptr(m) as *const ())?;
+                let entries = m.borrow().clone();
+                self.sequence('{', '}', &entries, |writer, (key, value)| {
+                    writer.entry(key, value)
+                })?;
+                self.stack.pop();
+            }
+            Evaluation::ClassInstance(ci) => {
+                self.enter(Rc::as_ptr(ci) as *const ())?;
+                // Fields are stored unordered, sort them to keep the output stable
+                let mut fields = ci
+                    .borrow()
+                    .fields()
+                    .map(|(name, value)| (name.to_string(), value.clone()))
+                    .collect::<Vec<_>>();
+                fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+                self.sequence('{', '}', &fields, |writer, (key, value)| {
+                    writer.entry(key, value)
+                })?;
+                self.stack.pop();
+            }
+            Evaluation::Fn(_) | Evaluation::ClassMethod(_) | Evaluation::Class(_) => {
+                return Err(format!("{} can't be serialized", value.type_name()));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn sequence<T>(
+        &mut self,
+        open: char,
+        close: char,
+        items: &[T],
+        mut write_item: impl FnMut(&mut Self, &T) -> Result<(), String>,
+    ) -> Result<(), String> {
+        self.output.push(open);
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.output.push(',');
+            }
+            self.newline()?;
+            write_item(self, item)?;
+        }
+
+        if !items.is_empty() {
+            // The container itself is still on the stack, close one level up
+            let container = self.stack.pop();
+            self.newline()?;
+            self.stack.extend(container);
+        }
+        self.output.push(close);
+
+        Ok(())
+    }
+
+    fn entry(&mut self, key: &str, value: &Evaluation<'_>) -> Result<(), String> {
+        self.string(key);
+        self.output.push(':');
+        if self.indent > 0 {
+            self.output.push(' ');
+        }
+
+        self.value(value)
+    }
+
+    fn string(&mut self, s: &str) {
+        self.output.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => self.output.push_str("\\\""),
+                '\\' => self.output.push_str("\\\\"),
+                '\n' => self.output.push_str("\\n"),
+                '\r' => self.output.push_str("\\r"),
+                '\t' => self.output.push_str("\\t"),
+                '\u{8}' => self.output.push_str("\\b"),
+                '\u{c}' => self.output.push_str("\\f"),
+                c if (c as u32) < 0x20 => {
+                    self.output.push_str(&format!("\\u{:04x}", c as u32));
+                }
+                c => self.output.push(c),
+            }
+        }
+        self.output.push('"');
+    }
+}
+
+pub(super) fn functions<'de>() -> Vec<(&'de str, Evaluation<'de>)> {
+    vec![
+        native("jsonParse", 1, |args| {
+            let source = expect_string("jsonParse", &args[0])?;
+
+            JsonParser::new(source)
+                .parse()
+                .map_err(|message| Interruption::Error(anyhow::anyhow! {"jsonParse() {message}"}))
+        }),
+        optional("jsonStringify", 1, 2, |args| {
+            let indent = match args.get(1) {
+                None | Some(Evaluation::Nil) => 0,
+                Some(indent) => match expect_integer("jsonStringify", indent)? {
+                    indent @ 0..=MAX_INDENT => indent as usize,
+                    indent => {
+                        return Err(Interruption::Error(anyhow::anyhow! {
+                            "jsonStringify() indent {indent} is out of range, expected 0 to {MAX_INDENT}."
+                        }))
+                    }
+                },
+            };
+
+            JsonWriter::new(indent)
+                .write(&args[0])
+                .map(Evaluation::String)
+                .map_err(|message| {
+                    Interruption::Error(anyhow::anyhow! {"jsonStringify() {message}."})
+                })
+        }),
+        native("keys", 1, |args| match &args[0] {
+            Evaluation::Map(m) => Ok(Evaluation::list(
+                m.borrow()
+                    .iter()
+                    .map(|(key, _)| Evaluation::String(key.clone()))
+                    .collect(),
+            )),
+            value => Err(type_error("keys", "a map", value)),
+        }),
+    ]
+}
@@ -0,0 +1,138 @@
+use std::{cmp::Ordering, f64::consts};
+
+use crate::{
+    ci::{interpreter::Interruption, Evaluation},
+    BigInt,
+};
+
+use super::{expect_number, native, type_error, variadic};
+
+fn unary<'de>(name: &'static str, operation: fn(f64) -> f64) -> (&'de str, Evaluation<'de>) {
+    native(name, 1, move |args| {
+        let x = expect_number(name, &args[0])?;
+
+        Ok(Evaluation::Number(operation(x)))
+    })
+}
+
+fn binary<'de>(name: &'static str, operation: fn(f64, f64) -> f64) -> (&'de str, Evaluation<'de>) {
+    native(name, 2, move |args| {
+        let x = expect_number(name, &args[0])?;
+        let y = expect_number(name, &args[1])?;
+
+        Ok(Evaluation::Number(operation(x, y)))
+    })
+}
+
+/// Integers are kept as they are, only floats are rounded.
+fn rounding<'de>(name: &'static str, operation: fn(f64) -> f64) -> (&'de str, Evaluation<'de>) {
+    native(name, 1, move |args| match &args[0] {
+        Evaluation::Integer(_) | Evaluation::BigInteger(_) => Ok(args[0].clone()),
+        value => Ok(Evaluation::Number(operation(expect_number(name, value)?))),
+    })
+}
+
+/// Picks the argument ordered as `pick` against all the others. Integers are compared exactly
+/// and keep their type, as soon as one argument is a float all of them are used as floats.
+fn fold<'de>(
+    name: &'static str,
+    pick: Ordering,
+    operation: fn(f64, f64) -> f64,
+) -> (&'de str, Evaluation<'de>) {
+    variadic(name, 1, move |args| {
+        if let Some(integers) = args
+            .iter()
+            .map(Evaluation::as_big_integer)
+            .collect::<Option<Vec<_>>>()
+        {
+            let index = (1..integers.len()).fold(0, |best, i| {
+                if integers[i].cmp(&integers[best]) == pick {
+                    i
+                } else {
+                    best
+                }
+            });
+
+            return Ok(args[index].clone());
+        }
+
+        let mut result = expect_number(name, &args[0])?;
+        for arg in args.iter().skip(1) {
+            result = operation(result, expect_number(name, arg)?);
+        }
+
+        Ok(Evaluation::Number(result))
+    })
+}
+
+fn predicate<'de>(name: &'static str, check: fn(f64) -> bool) -> (&'de str, Evaluation<'de>) {
+    native(name, 1, move |args| {
+        let x = expect_number(name, &args[0])?;
+
+        Ok(Evaluation::Boolean(check(x)))
+    })
+}
+
+pub(super) fn functions<'de>() -> Vec<(&'de str, Evaluation<'de>)> {
+    vec![
+        ("PI", Evaluation::Number(consts::PI)),
+        ("E", Evaluation::Number(consts::E)),
+        unary("sqrt", f64::sqrt),
+        native("pow", 2, |args| {
+            // Same as `**`, integers to a non-negative integer power stay exact
+            let result = args[0].pow(&args[1]).map_err(Interruption::Error)?;
+
+            result.ok_or_else(|| {
+                let value = match args[0].as_number() {
+                    Some(_) => &args[1],
+                    None => &args[0],
+                };
+
+                type_error("pow", "a number", value)
+            })
+        }),
+        native("abs", 1, |args| match &args[0] {
+            Evaluation::Integer(n) => Ok(Evaluation::integer_or(n.checked_abs(), || {
+                -&BigInt::from(*n)
+            })),
+            Evaluation::BigInteger(n) if *n < BigInt::from(0) => Ok(Evaluation::integer(-n)),
+            Evaluation::BigInteger(_) => Ok(args[0].clone()),
+            value => Ok(Evaluation::Number(expect_number("abs", value)?.abs())),
+        }),
+        rounding("floor", f64::floor),
+        rounding("ceil", f64::ceil),
+        rounding("round", f64::round),
+        fold("min", Ordering::Less, f64::min),
+        fold("max", Ordering::Greater, f64::max),
+        unary("sin", f64::sin),
+        unary("cos", f64::cos),
+        unary("tan", f64::tan),
+        binary("atan2", f64::atan2),
+        unary("log", f64::ln),
+        unary("exp", f64::exp),
+        predicate("isNaN", f64::is_nan),
+        predicate("isInfinite", f64::is_infinite),
+        native("isInteger", 1, |args| {
+            Ok(Evaluation::Boolean(matches!(
+                args[0],
+                Evaluation::Integer(_) | Evaluation::BigInteger(_)
+            )))
+        }),
+        native("int", 1, |args| {
+            let x = expect_number("int", &args[0])?;
+            if let Some(n) = args[0].as_big_integer() {
+                return Ok(Evaluation::integer(n));
+            }
+
+            // Truncates towards zero, like `~/`
+            BigInt::from_f64(x)
+                .map(Evaluation::integer)
+                .ok_or(Interruption::Error(anyhow::anyhow! {
+                    "int() {x} doesn't fit in an integer."
+                }))
+        }),
+        native("float", 1, |args| {
+            Ok(Evaluation::Number(expect_number("float", &args[0])?))
+        }),
+    ]
+}
@@ -0,0 +1,171 @@
+mod io;
+mod json;
+mod math;
+mod random;
+mod reflection;
+mod script;
+mod string;
+
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    ci::{interpreter::Interruption, Evaluation, RuntimeOptions},
+    Token,
+};
+
+use super::{Callable, Function};
+
+pub type NativeBody<'de> =
+    Rc<dyn Fn(Vec<Evaluation<'de>>) -> Result<Evaluation<'de>, Interruption<'de>> + 'de>;
+
+pub struct NativeFunction<'de> {
+    pub name: &'static str,
+    pub arity: usize,
+    /// Most arguments the function takes, `None` when there is no limit
+    pub max_arity: Option<usize>,
+    #[allow(unused)]
+    pub parameters: Vec<Token<'de>>,
+    pub body: NativeBody<'de>,
+}
+
+impl<'de> Callable<'de> for NativeFunction<'de> {
+    fn call(
+        &self,
+        arguments: impl IntoIterator<Item = Evaluation<'de>>,
+    ) -> Result<Evaluation<'de>, Interruption<'de>> {
+        (self.body)(arguments.into_iter().collect())
+    }
+}
+
+pub(crate) fn native<'de>(
+    name: &'static str,
+    arity: usize,
+    body: impl Fn(Vec<Evaluation<'de>>) -> Result<Evaluation<'de>, Interruption<'de>> + 'de,
+) -> (&'de str, Evaluation<'de>) {
+    native_function(name, arity, Some(arity), body)
+}
+
+pub(crate) fn variadic<'de>(
+    name: &'static str,
+    min_arity: usize,
+    body: impl Fn(Vec<Evaluation<'de>>) -> Result<Evaluation<'de>, Interruption<'de>> + 'de,
+) -> (&'de str, Evaluation<'de>) {
+    native_function(name, min_arity, None, body)
+}
+
+/// A native taking from `min_arity` to `max_arity` arguments, the optional ones may be missing.
+pub(crate) fn optional<'de>(
+    name: &'static str,
+    min_arity: usize,
+    max_arity: usize,
+    body: impl Fn(Vec<Evaluation<'de>>) -> Result<Evaluation<'de>, Interruption<'de>> + 'de,
+) -> (&'de str, Evaluation<'de>) {
+    native_function(name, min_arity, Some(max_arity), body)
+}
+
+fn native_function<'de>(
+    name: &'static str,
+    arity: usize,
+    max_arity: Option<usize>,
+    body: impl Fn(Vec<Evaluation<'de>>) -> Result<Evaluation<'de>, Interruption<'de>> + 'de,
+) -> (&'de str, Evaluation<'de>) {
+    let function = Rc::new(Function::NativeFunction(Box::new(NativeFunction {
+        name,
+        arity,
+        max_arity,
+        parameters: vec![],
+        body: Rc::new(body),
+    })));
+
+    (name, Evaluation::Fn(function))
+}
+
+// TODO: static
+pub fn native_functions<'de>(options: &RuntimeOptions) -> HashMap<&'de str, Evaluation<'de>> {
+    let clock_fn = native("clock", 0, |_| {
+        let now = SystemTime::now();
+        let result = match now.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs(),
+            Err(e) => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Could not get clock: {e}"},
+                ))
+            }
+        };
+
+        return Ok(Evaluation::Integer(result as i64));
+    });
+
+    [clock_fn]
+        .into_iter()
+        .chain(io::functions(options))
+        .chain(json::functions())
+        .chain(math::functions())
+        .chain(random::functions(options))
+        .chain(reflection::functions())
+        .chain(script::functions(options))
+        .chain(string::functions())
+        .collect()
+}
+
+pub(crate) fn type_error<'de>(
+    function: &str,
+    expected: &str,
+    value: &Evaluation<'de>,
+) -> Interruption<'de> {
+    Interruption::Error(anyhow::anyhow! {
+        "{function}() expects {expected} but got {actual}.",
+        actual = value.type_name()
+    })
+}
+
+pub(crate) fn expect_string<'a, 'de>(
+    function: &str,
+    value: &'a Evaluation<'de>,
+) -> Result<&'a str, Interruption<'de>> {
+    match value {
+        Evaluation::String(s) => Ok(s),
+        _ => Err(type_error(function, "a string", value)),
+    }
+}
+
+pub(crate) fn expect_number<'de>(
+    function: &str,
+    value: &Evaluation<'de>,
+) -> Result<f64, Interruption<'de>> {
+    value
+        .as_number()
+        .ok_or_else(|| type_error(function, "a number", value))
+}
+
+/// 2^63, the first float past `i64::MAX`
+const I64_BOUND: f64 = 9_223_372_036_854_775_808.0;
+
+pub(crate) fn expect_integer<'de>(
+    function: &str,
+    value: &Evaluation<'de>,
+) -> Result<i64, Interruption<'de>> {
+    match value {
+        Evaluation::Integer(n) => Ok(*n),
+        // `as` would saturate, so whole floats beyond i64 are rejected
+        Evaluation::Number(n) if n.fract() == 0.0 && (-I64_BOUND..I64_BOUND).contains(n) => {
+            Ok(*n as i64)
+        }
+        _ => Err(type_error(function, "an integer", value)),
+    }
+}
+
+pub(crate) fn expect_list<'de>(
+    function: &str,
+    value: &Evaluation<'de>,
+) -> Result<Rc<RefCell<Vec<Evaluation<'de>>>>, Interruption<'de>> {
+    match value {
+        Evaluation::List(list) => Ok(list.clone()),
+        _ => Err(type_error(function, "a list", value)),
+    }
+}
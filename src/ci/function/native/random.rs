@@ -0,0 +1,129 @@
+use std::{
+    cell::RefCell,
+    rc::Rc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::ci::{interpreter::Interruption, Evaluation, RuntimeOptions};
+
+use super::{expect_integer, expect_list, native};
+
+/// SplitMix64 generator: tiny, fast and fully determined by its seed, so that a run
+/// started with the same `--seed` produces exactly the same numbers.
+struct Random {
+    state: u64,
+}
+
+impl Random {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform float in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform integer in `[0, bound)`, rejects the tail to avoid modulo bias.
+    fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0);
+
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % bound;
+            }
+        }
+    }
+}
+
+fn time_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+pub(super) fn functions<'de>(options: &RuntimeOptions) -> Vec<(&'de str, Evaluation<'de>)> {
+    let random = Rc::new(RefCell::new(Random::new(
+        options.seed.unwrap_or_else(time_seed),
+    )));
+
+    vec![
+        native("seed", 1, {
+            let random = random.clone();
+            move |args| {
+                let seed = expect_integer("seed", &args[0])?;
+                *random.borrow_mut() = Random::new(seed as u64);
+
+                Ok(Evaluation::Nil)
+            }
+        }),
+        native("random", 0, {
+            let random = random.clone();
+            move |_| Ok(Evaluation::Number(random.borrow_mut().next_f64()))
+        }),
+        native("randomInt", 2, {
+            let random = random.clone();
+            move |args| {
+                let lo = expect_integer("randomInt", &args[0])?;
+                let hi = expect_integer("randomInt", &args[1])?;
+                if lo > hi {
+                    return Err(Interruption::Error(anyhow::anyhow! {
+                        "randomInt() lower bound {lo} is greater than upper bound {hi}."
+                    }));
+                }
+
+                let mut random_ = random.borrow_mut();
+                // The whole i64 range has 2^64 values, one more than fits the span
+                let Some(span) = hi.abs_diff(lo).checked_add(1) else {
+                    return Ok(Evaluation::Integer(random_.next_u64() as i64));
+                };
+                let offset = random_.below(span);
+
+                Ok(Evaluation::Integer(lo.wrapping_add(offset as i64)))
+            }
+        }),
+        native("shuffle", 1, {
+            let random = random.clone();
+            move |args| {
+                let list = expect_list("shuffle", &args[0])?;
+
+                {
+                    let mut list_ = list.borrow_mut();
+                    let mut random_ = random.borrow_mut();
+                    // Fisher-Yates
+                    for i in (1..list_.len()).rev() {
+                        let j = random_.below(i as u64 + 1) as usize;
+                        list_.swap(i, j);
+                    }
+                }
+
+                Ok(Evaluation::List(list))
+            }
+        }),
+        native("choice", 1, move |args| {
+            let list = expect_list("choice", &args[0])?;
+            let list_ = list.borrow();
+            if list_.is_empty() {
+                return Err(Interruption::Error(anyhow::anyhow! {
+                    "choice() expects a non-empty list."
+                }));
+            }
+
+            let i = random.borrow_mut().below(list_.len() as u64) as usize;
+
+            Ok(list_[i].clone())
+        }),
+    ]
+}
@@ -0,0 +1,115 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::ci::{class::INIT_STR, interpreter::Interruption, Class, ClassInstance, Evaluation};
+
+use super::{expect_string, native, type_error};
+
+fn expect_instance<'de>(
+    function: &str,
+    value: &Evaluation<'de>,
+) -> Result<Rc<RefCell<ClassInstance<'de>>>, Interruption<'de>> {
+    match value {
+        Evaluation::ClassInstance(class_instance) => Ok(class_instance.clone()),
+        _ => Err(type_error(function, "an instance", value)),
+    }
+}
+
+fn expect_class<'de>(
+    function: &str,
+    value: &Evaluation<'de>,
+) -> Result<Rc<Class<'de>>, Interruption<'de>> {
+    match value {
+        Evaluation::Class(class) => Ok(class.clone()),
+        _ => Err(type_error(function, "a class", value)),
+    }
+}
+
+pub(super) fn functions<'de>() -> Vec<(&'de str, Evaluation<'de>)> {
+    vec![
+        native("typeOf", 1, |args| {
+            Ok(Evaluation::String(args[0].type_name().to_string()))
+        }),
+        native("classOf", 1, |args| {
+            let class_instance = expect_instance("classOf", &args[0])?;
+            let class = class_instance.borrow().class().clone();
+
+            Ok(Evaluation::Class(class))
+        }),
+        native("fieldsOf", 1, |args| {
+            let class_instance = expect_instance("fieldsOf", &args[0])?;
+
+            // Fields are stored unordered, sort them to keep the output stable
+            let mut names = class_instance
+                .borrow()
+                .fields()
+                .map(|(name, _)| name.to_string())
+                .collect::<Vec<_>>();
+            names.sort();
+
+            Ok(Evaluation::list(
+                names.into_iter().map(Evaluation::String).collect(),
+            ))
+        }),
+        native("hasField", 2, |args| {
+            let class_instance = expect_instance("hasField", &args[0])?;
+            let name = expect_string("hasField", &args[1])?;
+
+            let has_field = class_instance.borrow().has_field(name);
+
+            Ok(Evaluation::Boolean(has_field))
+        }),
+        native("getField", 2, |args| {
+            let class_instance = expect_instance("getField", &args[0])?;
+            let name = expect_string("getField", &args[1])?;
+
+            let field = class_instance.borrow().get_field(name);
+
+            field.map_err(Interruption::Error)
+        }),
+        native("setField", 3, |mut args| {
+            let class_instance = expect_instance("setField", &args[0])?;
+            let name = expect_string("setField", &args[1])?.to_string();
+            let value = args.pop().expect("Arity is checked before the call");
+
+            class_instance
+                .borrow_mut()
+                .set_field(name, value.clone())
+                .map_err(Interruption::Error)?;
+
+            Ok(value)
+        }),
+        native("superclassOf", 1, |args| {
+            let class = expect_class("superclassOf", &args[0])?;
+
+            Ok(class
+                .super_class()
+                .map(Evaluation::Class)
+                .unwrap_or(Evaluation::Nil))
+        }),
+        native("isInstance", 2, |args| {
+            let class = expect_class("isInstance", &args[1])?;
+
+            let is_instance = match &args[0] {
+                Evaluation::ClassInstance(class_instance) => {
+                    class_instance.borrow().class().is_subclass_of(&class)
+                }
+                _ => false,
+            };
+
+            Ok(Evaluation::Boolean(is_instance))
+        }),
+        native("arity", 1, |args| {
+            let arity = match &args[0] {
+                Evaluation::Fn(func) => func.min_arity(),
+                Evaluation::ClassMethod(class_method) => class_method.func.min_arity(),
+                Evaluation::Class(class) => class
+                    .find_method(INIT_STR)
+                    .map(|init| init.min_arity())
+                    .unwrap_or(0),
+                value => return Err(type_error("arity", "a function or a class", value)),
+            };
+
+            Ok(Evaluation::Integer(arity as i64))
+        }),
+    ]
+}
@@ -0,0 +1,47 @@
+use std::env;
+
+use crate::ci::{interpreter::Interruption, Evaluation, RuntimeOptions};
+
+use super::{expect_integer, expect_string, native};
+
+pub(super) fn functions<'de>(options: &RuntimeOptions) -> Vec<(&'de str, Evaluation<'de>)> {
+    let args = options.args.clone();
+
+    vec![
+        native("args", 0, move |_| {
+            Ok(Evaluation::list(
+                args.iter().cloned().map(Evaluation::String).collect(),
+            ))
+        }),
+        native("getenv", 1, |args| {
+            let name = expect_string("getenv", &args[0])?;
+
+            Ok(env::var(name)
+                .map(Evaluation::String)
+                .unwrap_or(Evaluation::Nil))
+        }),
+        native("setenv", 2, |args| {
+            let name = expect_string("setenv", &args[0])?;
+            let value = expect_string("setenv", &args[1])?;
+
+            if name.is_empty() || name.contains(['=', '\0']) || value.contains('\0') {
+                return Err(Interruption::Error(anyhow::anyhow! {
+                    "setenv() invalid environment variable '{name}'."
+                }));
+            }
+            env::set_var(name, value);
+
+            Ok(Evaluation::Nil)
+        }),
+        native("exit", 1, |args| {
+            let code = expect_integer("exit", &args[0])?;
+            // The OS keeps only the low 8 bits, so 256 would exit with 0
+            let code = u8::try_from(code).map_err(|_| {
+                Interruption::Error(anyhow::anyhow! {"exit() code {code} is out of range."})
+            })?;
+
+            // Unwinds through all the calls up to the interpreter loop
+            Err(Interruption::Exit(code.into()))
+        }),
+    ]
+}
@@ -0,0 +1,180 @@
+use crate::{
+    ci::{interpreter::Interruption, Evaluation},
+    BigInt,
+};
+
+use super::{expect_integer, expect_list, expect_string, native, type_error};
+
+/// Converts a char position into a byte offset, positions are counted in chars so that
+/// non-ASCII strings are indexed the same way they are printed.
+fn byte_offset<'de>(function: &str, s: &str, position: i64) -> Result<usize, Interruption<'de>> {
+    let len = s.chars().count();
+    if position < 0 || position as usize > len {
+        return Err(Interruption::Error(anyhow::anyhow! {
+            "{function}() position {position} out of bounds for string of length {len}."
+        }));
+    }
+
+    Ok(s.char_indices()
+        .nth(position as usize)
+        .map(|(offset, _)| offset)
+        .unwrap_or(s.len()))
+}
+
+fn char_position(s: &str, byte_offset: usize) -> usize {
+    s[..byte_offset].chars().count()
+}
+
+fn is_number_literal(s: &str) -> bool {
+    !s.is_empty()
+        && s.chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | '-' | '+' | 'e' | 'E'))
+}
+
+pub(super) fn functions<'de>() -> Vec<(&'de str, Evaluation<'de>)> {
+    vec![
+        native("len", 1, |args| {
+            let len = match &args[0] {
+                Evaluation::String(s) => s.chars().count(),
+                Evaluation::List(l) => l.borrow().len(),
+                Evaluation::Map(m) => m.borrow().len(),
+                value => return Err(type_error("len", "a string, a list or a map", value)),
+            };
+
+            Ok(Evaluation::Integer(len as i64))
+        }),
+        native("substring", 3, |args| {
+            let s = expect_string("substring", &args[0])?;
+            let start = byte_offset("substring", s, expect_integer("substring", &args[1])?)?;
+            let end = byte_offset("substring", s, expect_integer("substring", &args[2])?)?;
+
+            if start > end {
+                return Err(Interruption::Error(anyhow::anyhow! {
+                    "substring() start must not be greater than end."
+                }));
+            }
+
+            Ok(Evaluation::String(s[start..end].to_string()))
+        }),
+        native("indexOf", 2, |args| {
+            let s = expect_string("indexOf", &args[0])?;
+            let pattern = expect_string("indexOf", &args[1])?;
+
+            let position = s
+                .find(pattern)
+                .map(|offset| char_position(s, offset) as i64)
+                .unwrap_or(-1);
+
+            Ok(Evaluation::Integer(position))
+        }),
+        native("split", 2, |args| {
+            let s = expect_string("split", &args[0])?;
+            let separator = expect_string("split", &args[1])?;
+
+            let parts = if separator.is_empty() {
+                s.chars()
+                    .map(|c| Evaluation::String(c.to_string()))
+                    .collect()
+            } else {
+                s.split(separator)
+                    .map(|part| Evaluation::String(part.to_string()))
+                    .collect()
+            };
+
+            Ok(Evaluation::list(parts))
+        }),
+        native("join", 2, |args| {
+            let list = expect_list("join", &args[0])?;
+            let separator = expect_string("join", &args[1])?;
+
+            let joined = list
+                .borrow()
+                .iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>()
+                .join(separator);
+
+            Ok(Evaluation::String(joined))
+        }),
+        native("trim", 1, |args| {
+            let s = expect_string("trim", &args[0])?;
+
+            Ok(Evaluation::String(s.trim().to_string()))
+        }),
+        native("upper", 1, |args| {
+            let s = expect_string("upper", &args[0])?;
+
+            Ok(Evaluation::String(s.to_uppercase()))
+        }),
+        native("lower", 1, |args| {
+            let s = expect_string("lower", &args[0])?;
+
+            Ok(Evaluation::String(s.to_lowercase()))
+        }),
+        native("replace", 3, |args| {
+            let s = expect_string("replace", &args[0])?;
+            let from = expect_string("replace", &args[1])?;
+            let to = expect_string("replace", &args[2])?;
+
+            Ok(Evaluation::String(s.replace(from, to)))
+        }),
+        native("startsWith", 2, |args| {
+            let s = expect_string("startsWith", &args[0])?;
+            let prefix = expect_string("startsWith", &args[1])?;
+
+            Ok(Evaluation::Boolean(s.starts_with(prefix)))
+        }),
+        native("endsWith", 2, |args| {
+            let s = expect_string("endsWith", &args[0])?;
+            let suffix = expect_string("endsWith", &args[1])?;
+
+            Ok(Evaluation::Boolean(s.ends_with(suffix)))
+        }),
+        native("charAt", 2, |args| {
+            let _ = expect_string("charAt", &args[0])?;
+
+            args[0].get_index(&args[1]).map_err(Interruption::Error)
+        }),
+        native("ord", 1, |args| {
+            let s = expect_string("ord", &args[0])?;
+
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Ok(Evaluation::Integer(c as i64)),
+                _ => Err(Interruption::Error(anyhow::anyhow! {
+                    "ord() expects a single character string."
+                })),
+            }
+        }),
+        native("chr", 1, |args| {
+            let code = expect_integer("chr", &args[0])?;
+
+            u32::try_from(code)
+                .ok()
+                .and_then(char::from_u32)
+                .map(|c| Evaluation::String(c.to_string()))
+                .ok_or(Interruption::Error(anyhow::anyhow! {
+                    "chr() {code} is not a valid code point."
+                }))
+        }),
+        native("str", 1, |args| Ok(Evaluation::String(args[0].to_string()))),
+        native("num", 1, |args| {
+            let s = expect_string("num", &args[0])?.trim();
+
+            if !is_number_literal(s) {
+                return Ok(Evaluation::Nil);
+            }
+
+            if let Ok(integer) = s.parse::<i64>() {
+                return Ok(Evaluation::Integer(integer));
+            }
+            if let Some(integer) = BigInt::parse_radix(s, 10) {
+                return Ok(Evaluation::BigInteger(integer));
+            }
+
+            Ok(s.parse::<f64>()
+                .map(Evaluation::Number)
+                .unwrap_or(Evaluation::Nil))
+        }),
+    ]
+}
@@ -10,13 +10,18 @@ pub trait Debuge {
         name: &str,
         exprs: impl IntoIterator<Item = &'a Node<'a>>,
     ) -> String {
+        return self.parenthesize_parts(name, exprs.into_iter().map(|expr| expr.print()));
+    }
+
+    /// Like `parenthesize`, for parts that are not plain nodes, e.g. named arguments
+    fn parenthesize_parts(&self, name: &str, parts: impl IntoIterator<Item = String>) -> String {
         let mut s = String::new();
         s.push('(');
         s.push_str(name);
 
-        for expr in exprs.into_iter() {
+        for part in parts.into_iter() {
             s.push(' ');
-            s.push_str(&expr.print());
+            s.push_str(&part);
         }
         s.push(')');
 
@@ -28,7 +33,11 @@ pub trait Debuge {
 impl<'de> Debuge for VarDecl<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            &format!("var {name}", name = self.name),
+            &format!(
+                "{keyword} {name}",
+                keyword = if self.constant { "const" } else { "var" },
+                name = self.name.lexeme
+            ),
             if let Some(initializer) = &self.initializer {
                 vec![initializer.as_ref()]
             } else {
@@ -40,7 +49,11 @@ impl<'de> Debuge for VarDecl<'de> {
 impl<'de> Debuge for FunctionDecl<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            &format!("fun {name}", name = self.name),
+            &format!(
+                "fun {name}({parameters})",
+                name = self.name.lexeme,
+                parameters = parameters(&self.parameters)
+            ),
             vec![self.body.as_ref()],
         );
     }
@@ -48,24 +61,59 @@ impl<'de> Debuge for FunctionDecl<'de> {
 impl<'de> Debuge for ClassMethodDecl<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            &format!("method {name}", name = self.name),
+            &format!(
+                "method {name}({parameters})",
+                name = self.name.lexeme,
+                parameters = parameters(&self.parameters)
+            ),
             vec![self.body.as_ref()],
         );
     }
 }
 impl<'de> Debuge for ClassDecl<'de> {
     fn print(&self) -> String {
-        return self.parenthesize(&format!("class {name}", name = self.name), vec![]);
+        let name = match &self.super_class {
+            Some(super_class) => format!(
+                "class {name} < {super_class}",
+                name = self.name.lexeme,
+                super_class = super_class.name.lexeme
+            ),
+            None => format!("class {name}", name = self.name.lexeme),
+        };
+        let name = if self.final_fields.is_empty() {
+            name
+        } else {
+            let fields = self
+                .final_fields
+                .iter()
+                .map(|field| field.lexeme)
+                .collect::<Vec<_>>();
+            format!("{name} final {fields}", fields = fields.join(", "))
+        };
+
+        return self.parenthesize(&name, self.methods.iter().map(|method| method.as_ref()));
     }
 }
 impl<'de> Debuge for SuperClassDecl<'de> {
     fn print(&self) -> String {
-        return self.parenthesize(&format!("class {name}", name = self.name), vec![]);
+        return self.parenthesize(&format!("class {name}", name = self.name.lexeme), vec![]);
     }
 }
 
+fn parameters(parameters: &[Parameter<'_>]) -> String {
+    return parameters
+        .iter()
+        .map(|parameter| match (&parameter.default, parameter.rest) {
+            (_, true) => format!("...{}", parameter.name.lexeme),
+            (Some(default), _) => format!("{} = {}", parameter.name.lexeme, default.print()),
+            (None, false) => parameter.name.lexeme.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+}
+
 // Expressions
-impl<'de> Debuge for NilExp {
+impl Debuge for NilExp {
     fn print(&self) -> String {
         return self.parenthesize("nil", vec![]);
     }
@@ -76,7 +124,8 @@ impl<'de> Debuge for LiteralExp<'de> {
             TokenType::NIL => "nil".to_string(),
             TokenType::TRUE => "true".to_string(),
             TokenType::FALSE => "false".to_string(),
-            TokenType::STRING(literal) => literal.to_string(),
+            TokenType::IDENTIFIER => self.name.lexeme.to_string(),
+            TokenType::STRING(literal) | TokenType::INTERPOLATION(literal) => literal.to_string(),
             TokenType::NUMBER(literal) => {
                 if literal.fract() == 0.0 {
                     format!("{:.1}", literal)
@@ -84,6 +133,8 @@ impl<'de> Debuge for LiteralExp<'de> {
                     format!("{}", literal)
                 }
             }
+            TokenType::INTEGER(literal) => format!("{}.0", literal),
+            TokenType::BIG_INTEGER(literal) => format!("{}.0", literal),
             token => format!("{:?}", token),
         }
     }
@@ -95,13 +146,13 @@ impl<'de> Debuge for GroupingExp<'de> {
 }
 impl<'de> Debuge for UnaryExp<'de> {
     fn print(&self) -> String {
-        return self.parenthesize(&self.operator.lexeme, vec![self.right.as_ref()]);
+        return self.parenthesize(self.operator.lexeme, vec![self.right.as_ref()]);
     }
 }
 impl<'de> Debuge for BinaryExp<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            &self.operator.lexeme,
+            self.operator.lexeme,
             vec![self.left.as_ref(), self.right.as_ref()],
         );
     }
@@ -109,59 +160,103 @@ impl<'de> Debuge for BinaryExp<'de> {
 impl<'de> Debuge for LogicalExp<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            &self.operator.lexeme,
+            self.operator.lexeme,
             vec![self.left.as_ref(), self.right.as_ref()],
         );
     }
 }
-impl<'de> Debuge for AssignmentExp<'de> {
+impl<'de> Debuge for ConditionalExp<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            &format!("{name}", name = &self.name.lexeme),
-            vec![self.value.as_ref()],
+            "?:",
+            vec![
+                self.condition.as_ref(),
+                self.then_branch.as_ref(),
+                self.else_branch.as_ref(),
+            ],
         );
     }
 }
-impl<'de> Debuge for CallExp<'de> {
+impl<'de> Debuge for CoalesceExp<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            "fn",
-            vec![self.callee.as_ref()]
-                .into_iter()
-                .chain(self.args.iter().map(|arg| arg.as_ref())),
+            self.operator.lexeme,
+            vec![self.left.as_ref(), self.right.as_ref()],
         );
     }
 }
+impl<'de> Debuge for AssignmentExp<'de> {
+    fn print(&self) -> String {
+        let name = match &self.operator {
+            Some(operator) => format!("{} {}=", self.name.lexeme, operator.lexeme),
+            None => self.name.lexeme.to_string(),
+        };
+
+        return self.parenthesize(&name, vec![self.value.as_ref()]);
+    }
+}
+impl<'de> Debuge for CallExp<'de> {
+    fn print(&self) -> String {
+        let positional = std::iter::once(self.callee.as_ref())
+            .chain(self.args.iter().map(|arg| arg.as_ref()))
+            .map(|expr| expr.print());
+        let named = self
+            .named_args
+            .iter()
+            .map(|named_arg| format!("{}: {}", named_arg.name.lexeme, named_arg.value.print()));
+
+        return self.parenthesize_parts("fn", positional.chain(named));
+    }
+}
 
 impl<'de> Debuge for GetExp<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            &format!("get {}", self.name.lexeme),
+            &format!(
+                "{} {}",
+                if self.optional { "get?" } else { "get" },
+                self.name.lexeme
+            ),
             vec![self.callee.as_ref()],
         );
     }
 }
-impl<'de> Debuge for SetExp<'de> {
+impl<'de> Debuge for IndexExp<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            &format!("set"),
-            vec![self.get_exp.as_ref(), self.value.as_ref()],
+            if self.optional { "index?" } else { "index" },
+            vec![self.object.as_ref(), self.index.as_ref()],
         );
     }
 }
+impl<'de> Debuge for InterpolationExp<'de> {
+    fn print(&self) -> String {
+        return self.parenthesize("interpolate", self.parts.iter().map(|part| part.as_ref()));
+    }
+}
+impl<'de> Debuge for SetExp<'de> {
+    fn print(&self) -> String {
+        let name = match &self.operator {
+            Some(operator) => format!("set {}=", operator.lexeme),
+            None => "set".to_string(),
+        };
+
+        return self.parenthesize(&name, vec![self.get_exp.as_ref(), self.value.as_ref()]);
+    }
+}
 impl<'de> Debuge for ThisExp<'de> {
     fn print(&self) -> String {
-        return self.parenthesize(&format!("this"), vec![]);
+        return self.parenthesize("this", vec![]);
     }
 }
 impl<'de> Debuge for SuperExp<'de> {
     fn print(&self) -> String {
-        return self.parenthesize(&format!("super"), vec![]);
+        return self.parenthesize(&format!("super {}", self.method.lexeme), vec![]);
     }
 }
 
 // Statements
-impl<'de> Debuge for EmptyStm {
+impl Debuge for EmptyStm {
     fn print(&self) -> String {
         String::new()
     }
@@ -174,7 +269,7 @@ impl<'de> Debuge for PrintStm<'de> {
 impl<'de> Debuge for BlockStm<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            &format!("{{}}"),
+            "{}",
             self.statements.iter().map(|statement| statement.as_ref()),
         );
     }
@@ -182,7 +277,7 @@ impl<'de> Debuge for BlockStm<'de> {
 impl<'de> Debuge for FuncBodyStm<'de> {
     fn print(&self) -> String {
         return self.parenthesize(
-            &format!("{{}}"),
+            "{}",
             self.statements.iter().map(|statement| statement.as_ref()),
         );
     }
@@ -193,7 +288,7 @@ impl<'de> Debuge for IfElseStm<'de> {
         if let Some(ref else_branch) = self.else_branch {
             exprs.push(else_branch.as_ref());
         }
-        return self.parenthesize(&format!("if"), exprs);
+        return self.parenthesize("if", exprs);
     }
 }
 impl<'de> Debuge for WhileStm<'de> {
@@ -7,17 +7,28 @@ use super::Node;
 pub struct VarDecl<'de> {
     pub name: Token<'de>,
     pub initializer: Option<Box<Node<'de>>>,
+    /// Declared with `const`, the binding can't be assigned again
+    pub constant: bool,
+}
+
+/// `name`, `name = default` or `...name`, the rest parameter collecting extra arguments in a list
+#[derive(Clone)]
+pub struct Parameter<'de> {
+    pub name: Token<'de>,
+    /// Evaluated on every call that leaves the parameter out
+    pub default: Option<Rc<Node<'de>>>,
+    pub rest: bool,
 }
 
 pub struct FunctionDecl<'de> {
     pub name: Token<'de>,
-    pub parameters: Vec<Token<'de>>,
+    pub parameters: Vec<Parameter<'de>>,
     pub body: Rc<Node<'de>>,
 }
 
 pub struct ClassMethodDecl<'de> {
     pub name: Token<'de>,
-    pub parameters: Vec<Token<'de>>,
+    pub parameters: Vec<Parameter<'de>>,
     pub body: Rc<Node<'de>>,
 }
 
@@ -25,6 +36,8 @@ pub struct ClassDecl<'de> {
     pub name: Token<'de>,
     pub super_class: Option<SuperClassDecl<'de>>,
     pub methods: Vec<Box<Node<'de>>>,
+    /// `final a, b;` fields, which can only be set once per instance
+    pub final_fields: Vec<Token<'de>>,
 }
 
 pub struct SuperClassDecl<'de> {
@@ -0,0 +1,459 @@
+use super::{declaration::*, expression::*, statement::*, Node};
+
+/// Graphviz rendering of the syntax tree, edges are labelled with the field they come from.
+pub trait Dotify {
+    fn dot(&self, graph: &mut DotGraph) -> usize;
+}
+
+pub struct DotGraph {
+    output: String,
+    nodes: usize,
+}
+
+impl DotGraph {
+    pub fn render<'a, 'de: 'a>(statements: impl IntoIterator<Item = &'a Node<'de>>) -> String {
+        let mut graph = DotGraph {
+            output: String::new(),
+            nodes: 0,
+        };
+
+        graph.output.push_str("digraph AST {\n");
+        graph.output.push_str("  node [shape=box];\n");
+
+        let statements = statements.into_iter().collect::<Vec<_>>();
+        let _ = graph.node("Program", Self::indexed("statements", statements));
+
+        graph.output.push_str("}\n");
+
+        graph.output
+    }
+
+    /// Writes a node and its subtrees, returns the id of the node.
+    fn node<'a, 'de: 'a>(&mut self, label: &str, edges: Vec<(String, &'a Node<'de>)>) -> usize {
+        let id = self.nodes;
+        self.nodes += 1;
+
+        self.output
+            .push_str(&format!("  n{id} [label={}];\n", Self::quote(label)));
+
+        for (edge, child) in edges {
+            let child_id = child.dot(self);
+            self.output.push_str(&format!(
+                "  n{id} -> n{child_id} [label={}];\n",
+                Self::quote(&edge)
+            ));
+        }
+
+        id
+    }
+
+    fn edge<'a, 'de: 'a>(name: &str, node: &'a Node<'de>) -> (String, &'a Node<'de>) {
+        (name.to_string(), node)
+    }
+
+    fn indexed<'a, 'de: 'a>(
+        name: &str,
+        nodes: impl IntoIterator<Item = &'a Node<'de>>,
+    ) -> Vec<(String, &'a Node<'de>)> {
+        nodes
+            .into_iter()
+            .enumerate()
+            .map(|(i, node)| (format!("{name}[{i}]"), node))
+            .collect()
+    }
+
+    fn quote(text: &str) -> String {
+        let mut s = String::new();
+        s.push('"');
+        for c in text.chars() {
+            match c {
+                '"' => s.push_str("\\\""),
+                '\\' => s.push_str("\\\\"),
+                '\n' => s.push_str("\\n"),
+                c => s.push(c),
+            }
+        }
+        s.push('"');
+
+        s
+    }
+}
+
+fn parameters(parameters: &[Parameter<'_>]) -> String {
+    parameters
+        .iter()
+        // Defaults hang off the node as edges
+        .map(|parameter| match parameter.rest {
+            true => format!("...{}", parameter.name.lexeme),
+            false => parameter.name.lexeme.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// An edge to every default value, named after its parameter.
+fn defaults<'a, 'de: 'a>(parameters: &'a [Parameter<'de>]) -> Vec<(String, &'a Node<'de>)> {
+    parameters
+        .iter()
+        .filter_map(|parameter| {
+            let default = parameter.default.as_deref()?;
+            Some((format!("default {}", parameter.name.lexeme), default))
+        })
+        .collect()
+}
+
+impl<'de> Dotify for Node<'de> {
+    #[rustfmt::skip]
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        match self {
+            // Declarations
+            Node::VarDecl(var_decl) => var_decl.dot(graph),
+            Node::FunctionDecl(function_decl) => function_decl.dot(graph),
+            Node::ClassMethodDecl(class_method_decl) => class_method_decl.dot(graph),
+            Node::ClassDecl(class_decl) => class_decl.dot(graph),
+            Node::SuperClassDecl(super_class_decl) => super_class_decl.dot(graph),
+
+            // Expressions
+            Node::NilExp(nil_exp) => nil_exp.dot(graph),
+            Node::LiteralExp(literal_exp) => literal_exp.dot(graph),
+            Node::GroupingExp(grouping_exp) => grouping_exp.dot(graph),
+            Node::UnaryExp(unary_exp) => unary_exp.dot(graph),
+            Node::BinaryExp(binary_exp) => binary_exp.dot(graph),
+            Node::LogicalExp(logical_exp) => logical_exp.dot(graph),
+            Node::ConditionalExp(conditional_exp) => conditional_exp.dot(graph),
+            Node::CoalesceExp(coalesce_exp) => coalesce_exp.dot(graph),
+            Node::AssignmentExp(assignment_exp) => assignment_exp.dot(graph),
+            Node::CallExp(call_exp) => call_exp.dot(graph),
+            Node::GetExp(get_exp) => get_exp.dot(graph),
+            Node::IndexExp(index_exp) => index_exp.dot(graph),
+            Node::InterpolationExp(interpolation_exp) => interpolation_exp.dot(graph),
+            Node::SetExp(set_exp) => set_exp.dot(graph),
+            Node::ThisExp(this_exp) => this_exp.dot(graph),
+            Node::SuperExp(super_exp) => super_exp.dot(graph),
+
+            // Statements
+            Node::EmptyStm(empty_stm) => empty_stm.dot(graph),
+            Node::PrintStm(print_stm) => print_stm.dot(graph),
+            Node::BlockStm(block_stm) => block_stm.dot(graph),
+            Node::FuncBodyStm(func_body_stm) => func_body_stm.dot(graph),
+            Node::IfElseStm(if_else_stm) => if_else_stm.dot(graph),
+            Node::WhileStm(while_stm) => while_stm.dot(graph),
+            Node::ExpressionStm(expression_stm) => expression_stm.dot(graph),
+            Node::ReturnStm(return_stm) => return_stm.dot(graph),
+        }
+    }
+}
+
+// Declarations
+impl<'de> Dotify for VarDecl<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        let edges = self
+            .initializer
+            .iter()
+            .map(|initializer| DotGraph::edge("initializer", initializer))
+            .collect();
+
+        let label = if self.constant {
+            format!("VarDecl const {}", self.name.lexeme)
+        } else {
+            format!("VarDecl {}", self.name.lexeme)
+        };
+
+        return graph.node(&label, edges);
+    }
+}
+impl<'de> Dotify for FunctionDecl<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            &format!(
+                "FunctionDecl {}({})",
+                self.name.lexeme,
+                parameters(&self.parameters)
+            ),
+            defaults(&self.parameters)
+                .into_iter()
+                .chain([DotGraph::edge("body", &self.body)])
+                .collect(),
+        );
+    }
+}
+impl<'de> Dotify for ClassMethodDecl<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            &format!(
+                "ClassMethodDecl {}({})",
+                self.name.lexeme,
+                parameters(&self.parameters)
+            ),
+            defaults(&self.parameters)
+                .into_iter()
+                .chain([DotGraph::edge("body", &self.body)])
+                .collect(),
+        );
+    }
+}
+impl<'de> Dotify for ClassDecl<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        let label = match &self.super_class {
+            Some(super_class) => format!(
+                "ClassDecl {} < {}",
+                self.name.lexeme, super_class.name.lexeme
+            ),
+            None => format!("ClassDecl {}", self.name.lexeme),
+        };
+        let label = if self.final_fields.is_empty() {
+            label
+        } else {
+            let fields = self
+                .final_fields
+                .iter()
+                .map(|field| field.lexeme)
+                .collect::<Vec<_>>();
+            format!("{label} final {}", fields.join(", "))
+        };
+
+        return graph.node(
+            &label,
+            DotGraph::indexed("methods", self.methods.iter().map(|method| method.as_ref())),
+        );
+    }
+}
+impl<'de> Dotify for SuperClassDecl<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(&format!("SuperClassDecl {}", self.name.lexeme), vec![]);
+    }
+}
+
+// Expressions
+impl Dotify for NilExp {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node("NilExp", vec![]);
+    }
+}
+impl<'de> Dotify for LiteralExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(&format!("LiteralExp {}", self.name.lexeme), vec![]);
+    }
+}
+impl<'de> Dotify for GroupingExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node("GroupingExp", vec![DotGraph::edge("inner", &self.inner)]);
+    }
+}
+impl<'de> Dotify for UnaryExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            &format!("UnaryExp {}", self.operator.lexeme),
+            vec![DotGraph::edge("right", &self.right)],
+        );
+    }
+}
+impl<'de> Dotify for BinaryExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            &format!("BinaryExp {}", self.operator.lexeme),
+            vec![
+                DotGraph::edge("left", &self.left),
+                DotGraph::edge("right", &self.right),
+            ],
+        );
+    }
+}
+impl<'de> Dotify for LogicalExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            &format!("LogicalExp {}", self.operator.lexeme),
+            vec![
+                DotGraph::edge("left", &self.left),
+                DotGraph::edge("right", &self.right),
+            ],
+        );
+    }
+}
+impl<'de> Dotify for ConditionalExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            "ConditionalExp",
+            vec![
+                DotGraph::edge("condition", &self.condition),
+                DotGraph::edge("then_branch", &self.then_branch),
+                DotGraph::edge("else_branch", &self.else_branch),
+            ],
+        );
+    }
+}
+impl<'de> Dotify for CoalesceExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            &format!("CoalesceExp {}", self.operator.lexeme),
+            vec![
+                DotGraph::edge("left", &self.left),
+                DotGraph::edge("right", &self.right),
+            ],
+        );
+    }
+}
+impl<'de> Dotify for AssignmentExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        let label = match &self.operator {
+            Some(operator) => format!("AssignmentExp {} {}=", self.name.lexeme, operator.lexeme),
+            None => format!("AssignmentExp {}", self.name.lexeme),
+        };
+
+        return graph.node(&label, vec![DotGraph::edge("value", &self.value)]);
+    }
+}
+impl<'de> Dotify for CallExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        let mut edges = vec![DotGraph::edge("callee", &self.callee)];
+        edges.extend(DotGraph::indexed(
+            "args",
+            self.args.iter().map(|arg| arg.as_ref()),
+        ));
+        edges.extend(
+            self.named_args
+                .iter()
+                .map(|named_arg| DotGraph::edge(named_arg.name.lexeme, &named_arg.value)),
+        );
+
+        return graph.node("CallExp", edges);
+    }
+}
+impl<'de> Dotify for GetExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            &format!(
+                "GetExp {}{}",
+                if self.optional { "?." } else { "" },
+                self.name.lexeme
+            ),
+            vec![DotGraph::edge("callee", &self.callee)],
+        );
+    }
+}
+impl<'de> Dotify for IndexExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            if self.optional {
+                "IndexExp ?."
+            } else {
+                "IndexExp"
+            },
+            vec![
+                DotGraph::edge("object", &self.object),
+                DotGraph::edge("index", &self.index),
+            ],
+        );
+    }
+}
+impl<'de> Dotify for InterpolationExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            "InterpolationExp",
+            DotGraph::indexed("parts", self.parts.iter().map(|part| part.as_ref())),
+        );
+    }
+}
+impl<'de> Dotify for SetExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        let label = match &self.operator {
+            Some(operator) => format!("SetExp {}=", operator.lexeme),
+            None => "SetExp".to_string(),
+        };
+
+        return graph.node(
+            &label,
+            vec![
+                DotGraph::edge("get_exp", &self.get_exp),
+                DotGraph::edge("value", &self.value),
+            ],
+        );
+    }
+}
+impl<'de> Dotify for ThisExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node("ThisExp this", vec![]);
+    }
+}
+impl<'de> Dotify for SuperExp<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(&format!("SuperExp {}", self.method.lexeme), vec![]);
+    }
+}
+
+// Statements
+impl Dotify for EmptyStm {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node("EmptyStm", vec![]);
+    }
+}
+impl<'de> Dotify for PrintStm<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            "PrintStm",
+            vec![DotGraph::edge("expression", &self.expression)],
+        );
+    }
+}
+impl<'de> Dotify for BlockStm<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            "BlockStm",
+            DotGraph::indexed(
+                "statements",
+                self.statements.iter().map(|statement| statement.as_ref()),
+            ),
+        );
+    }
+}
+impl<'de> Dotify for FuncBodyStm<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            "FuncBodyStm",
+            DotGraph::indexed(
+                "statements",
+                self.statements.iter().map(|statement| statement.as_ref()),
+            ),
+        );
+    }
+}
+impl<'de> Dotify for IfElseStm<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        let mut edges = vec![
+            DotGraph::edge("condition", &self.condition),
+            DotGraph::edge("then_branch", &self.then_branch),
+        ];
+        if let Some(else_branch) = &self.else_branch {
+            edges.push(DotGraph::edge("else_branch", else_branch));
+        }
+
+        return graph.node("IfElseStm", edges);
+    }
+}
+impl<'de> Dotify for WhileStm<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            "WhileStm",
+            vec![
+                DotGraph::edge("condition", &self.condition),
+                DotGraph::edge("body", &self.body),
+            ],
+        );
+    }
+}
+impl<'de> Dotify for ExpressionStm<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            "ExpressionStm",
+            vec![DotGraph::edge("expression", &self.expression)],
+        );
+    }
+}
+impl<'de> Dotify for ReturnStm<'de> {
+    fn dot(&self, graph: &mut DotGraph) -> usize {
+        return graph.node(
+            "ReturnStm",
+            vec![DotGraph::edge("expression", &self.expression)],
+        );
+    }
+}
@@ -29,23 +29,61 @@ pub struct LogicalExp<'de> {
     pub right: Box<Node<'de>>,
 }
 
+pub struct ConditionalExp<'de> {
+    pub condition: Box<Node<'de>>,
+    pub then_branch: Box<Node<'de>>,
+    pub else_branch: Box<Node<'de>>,
+}
+
+/// `left ?? right`, `right` is only evaluated when `left` is nil
+pub struct CoalesceExp<'de> {
+    pub left: Box<Node<'de>>,
+    pub operator: Token<'de>,
+    pub right: Box<Node<'de>>,
+}
+
 pub struct AssignmentExp<'de> {
     pub name: Token<'de>,
+    /// Binary operator of a compound assignment, `+` for `+=`
+    pub operator: Option<Token<'de>>,
     pub value: Box<Node<'de>>,
 }
 
 pub struct CallExp<'de> {
     pub callee: Box<Node<'de>>,
     pub args: Vec<Box<Node<'de>>>,
+    /// `name: value` arguments, they always follow the positional ones
+    pub named_args: Vec<NamedArg<'de>>,
+}
+
+pub struct NamedArg<'de> {
+    pub name: Token<'de>,
+    pub value: Box<Node<'de>>,
 }
 
 pub struct GetExp<'de> {
     pub callee: Box<Node<'de>>,
     pub name: Token<'de>,
+    /// `callee?.name`, the rest of the chain is skipped when `callee` is nil
+    pub optional: bool,
+}
+
+pub struct IndexExp<'de> {
+    pub object: Box<Node<'de>>,
+    pub index: Box<Node<'de>>,
+    /// `object?.[index]`, the rest of the chain is skipped when `object` is nil
+    pub optional: bool,
+}
+
+/// String segments, as `LiteralExp`s, interleaved with the embedded expressions.
+pub struct InterpolationExp<'de> {
+    pub parts: Vec<Box<Node<'de>>>,
 }
 
 pub struct SetExp<'de> {
     pub get_exp: Box<Node<'de>>,
+    /// Binary operator of a compound assignment, `+` for `+=`
+    pub operator: Option<Token<'de>>,
     pub value: Box<Node<'de>>,
 }
 
@@ -0,0 +1,442 @@
+use crate::{Token, TokenType};
+
+use super::{declaration::*, expression::*, statement::*, Node};
+
+/// Machine-readable counterpart of `Debuge`, every node becomes a JSON object tagged with its `type`.
+pub trait Jsonify {
+    fn to_json(&self) -> String;
+
+    fn object<'a>(
+        &self,
+        kind: &str,
+        fields: impl IntoIterator<Item = (&'a str, String)>,
+    ) -> String {
+        let mut s = String::new();
+        s.push_str("{\"type\":");
+        s.push_str(&json_string(kind));
+
+        for (name, value) in fields.into_iter() {
+            s.push(',');
+            s.push_str(&json_string(name));
+            s.push(':');
+            s.push_str(&value);
+        }
+        s.push('}');
+
+        s
+    }
+}
+
+pub fn json_string(value: &str) -> String {
+    let mut s = String::new();
+    s.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => s.push_str("\\\""),
+            '\\' => s.push_str("\\\\"),
+            '\n' => s.push_str("\\n"),
+            '\r' => s.push_str("\\r"),
+            '\t' => s.push_str("\\t"),
+            c if (c as u32) < 0x20 => s.push_str(&format!("\\u{:04x}", c as u32)),
+            c => s.push(c),
+        }
+    }
+    s.push('"');
+
+    s
+}
+
+fn json_array<'a, T: Jsonify + 'a + ?Sized>(items: impl IntoIterator<Item = &'a T>) -> String {
+    let items = items
+        .into_iter()
+        .map(|item| item.to_json())
+        .collect::<Vec<_>>();
+
+    format!("[{}]", items.join(","))
+}
+
+fn json_option<T: Jsonify + ?Sized>(item: Option<&T>) -> String {
+    item.map(|item| item.to_json())
+        .unwrap_or_else(|| "null".to_string())
+}
+
+impl<'de> Jsonify for Token<'de> {
+    fn to_json(&self) -> String {
+        let literal = match &self.token_type {
+            TokenType::STRING(literal) | TokenType::INTERPOLATION(literal) => json_string(literal),
+            TokenType::NUMBER(literal) => literal.to_string(),
+            TokenType::INTEGER(literal) => literal.to_string(),
+            TokenType::BIG_INTEGER(literal) => literal.to_string(),
+            _ => "null".to_string(),
+        };
+        let type_name = format!("{:?}", self.token_type);
+        let type_name = type_name.split('(').next().unwrap();
+
+        return self.object(
+            type_name,
+            vec![
+                ("lexeme", json_string(self.lexeme)),
+                ("literal", literal),
+                ("line", self.line.to_string()),
+                ("column", self.column.to_string()),
+            ],
+        );
+    }
+}
+
+impl<'de> Jsonify for Node<'de> {
+    #[rustfmt::skip]
+    fn to_json(&self) -> String {
+        match self {
+            // Declarations
+            Node::VarDecl(var_decl) => var_decl.to_json(),
+            Node::FunctionDecl(function_decl) => function_decl.to_json(),
+            Node::ClassMethodDecl(class_method_decl) => class_method_decl.to_json(),
+            Node::ClassDecl(class_decl) => class_decl.to_json(),
+            Node::SuperClassDecl(super_class_decl) => super_class_decl.to_json(),
+
+            // Expressions
+            Node::NilExp(nil_exp) => nil_exp.to_json(),
+            Node::LiteralExp(literal_exp) => literal_exp.to_json(),
+            Node::GroupingExp(grouping_exp) => grouping_exp.to_json(),
+            Node::UnaryExp(unary_exp) => unary_exp.to_json(),
+            Node::BinaryExp(binary_exp) => binary_exp.to_json(),
+            Node::LogicalExp(logical_exp) => logical_exp.to_json(),
+            Node::ConditionalExp(conditional_exp) => conditional_exp.to_json(),
+            Node::CoalesceExp(coalesce_exp) => coalesce_exp.to_json(),
+            Node::AssignmentExp(assignment_exp) => assignment_exp.to_json(),
+            Node::CallExp(call_exp) => call_exp.to_json(),
+            Node::GetExp(get_exp) => get_exp.to_json(),
+            Node::IndexExp(index_exp) => index_exp.to_json(),
+            Node::InterpolationExp(interpolation_exp) => interpolation_exp.to_json(),
+            Node::SetExp(set_exp) => set_exp.to_json(),
+            Node::ThisExp(this_exp) => this_exp.to_json(),
+            Node::SuperExp(super_exp) => super_exp.to_json(),
+
+            // Statements
+            Node::EmptyStm(empty_stm) => empty_stm.to_json(),
+            Node::PrintStm(print_stm) => print_stm.to_json(),
+            Node::BlockStm(block_stm) => block_stm.to_json(),
+            Node::FuncBodyStm(func_body_stm) => func_body_stm.to_json(),
+            Node::IfElseStm(if_else_stm) => if_else_stm.to_json(),
+            Node::WhileStm(while_stm) => while_stm.to_json(),
+            Node::ExpressionStm(expression_stm) => expression_stm.to_json(),
+            Node::ReturnStm(return_stm) => return_stm.to_json(),
+        }
+    }
+}
+
+// Declarations
+impl<'de> Jsonify for VarDecl<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "VarDecl",
+            vec![
+                ("name", self.name.to_json()),
+                ("initializer", json_option(self.initializer.as_deref())),
+                ("constant", self.constant.to_string()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for Parameter<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "Parameter",
+            vec![
+                ("name", self.name.to_json()),
+                ("default", json_option(self.default.as_deref())),
+                ("rest", self.rest.to_string()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for FunctionDecl<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "FunctionDecl",
+            vec![
+                ("name", self.name.to_json()),
+                ("parameters", json_array(&self.parameters)),
+                ("body", self.body.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for ClassMethodDecl<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "ClassMethodDecl",
+            vec![
+                ("name", self.name.to_json()),
+                ("parameters", json_array(&self.parameters)),
+                ("body", self.body.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for ClassDecl<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "ClassDecl",
+            vec![
+                ("name", self.name.to_json()),
+                ("super_class", json_option(self.super_class.as_ref())),
+                (
+                    "methods",
+                    json_array(self.methods.iter().map(|method| method.as_ref())),
+                ),
+                ("final_fields", json_array(&self.final_fields)),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for SuperClassDecl<'de> {
+    fn to_json(&self) -> String {
+        return self.object("SuperClassDecl", vec![("name", self.name.to_json())]);
+    }
+}
+
+// Expressions
+impl Jsonify for NilExp {
+    fn to_json(&self) -> String {
+        return self.object("NilExp", vec![]);
+    }
+}
+impl<'de> Jsonify for LiteralExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object("LiteralExp", vec![("value", self.name.to_json())]);
+    }
+}
+impl<'de> Jsonify for GroupingExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object("GroupingExp", vec![("inner", self.inner.to_json())]);
+    }
+}
+impl<'de> Jsonify for UnaryExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "UnaryExp",
+            vec![
+                ("operator", self.operator.to_json()),
+                ("right", self.right.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for BinaryExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "BinaryExp",
+            vec![
+                ("left", self.left.to_json()),
+                ("operator", self.operator.to_json()),
+                ("right", self.right.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for LogicalExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "LogicalExp",
+            vec![
+                ("left", self.left.to_json()),
+                ("operator", self.operator.to_json()),
+                ("right", self.right.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for ConditionalExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "ConditionalExp",
+            vec![
+                ("condition", self.condition.to_json()),
+                ("then_branch", self.then_branch.to_json()),
+                ("else_branch", self.else_branch.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for CoalesceExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "CoalesceExp",
+            vec![
+                ("left", self.left.to_json()),
+                ("operator", self.operator.to_json()),
+                ("right", self.right.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for AssignmentExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "AssignmentExp",
+            vec![
+                ("name", self.name.to_json()),
+                ("operator", json_option(self.operator.as_ref())),
+                ("value", self.value.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for CallExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "CallExp",
+            vec![
+                ("callee", self.callee.to_json()),
+                ("args", json_array(self.args.iter().map(|arg| arg.as_ref()))),
+                ("named_args", json_array(&self.named_args)),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for NamedArg<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "NamedArg",
+            vec![
+                ("name", self.name.to_json()),
+                ("value", self.value.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for GetExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "GetExp",
+            vec![
+                ("callee", self.callee.to_json()),
+                ("name", self.name.to_json()),
+                ("optional", self.optional.to_string()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for IndexExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "IndexExp",
+            vec![
+                ("object", self.object.to_json()),
+                ("index", self.index.to_json()),
+                ("optional", self.optional.to_string()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for InterpolationExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "InterpolationExp",
+            vec![(
+                "parts",
+                json_array(self.parts.iter().map(|part| part.as_ref())),
+            )],
+        );
+    }
+}
+impl<'de> Jsonify for SetExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "SetExp",
+            vec![
+                ("get_exp", self.get_exp.to_json()),
+                ("operator", json_option(self.operator.as_ref())),
+                ("value", self.value.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for ThisExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object("ThisExp", vec![("token", self.token.to_json())]);
+    }
+}
+impl<'de> Jsonify for SuperExp<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "SuperExp",
+            vec![
+                ("token", self.token.to_json()),
+                ("method", self.method.to_json()),
+            ],
+        );
+    }
+}
+
+// Statements
+impl Jsonify for EmptyStm {
+    fn to_json(&self) -> String {
+        return self.object("EmptyStm", vec![]);
+    }
+}
+impl<'de> Jsonify for PrintStm<'de> {
+    fn to_json(&self) -> String {
+        return self.object("PrintStm", vec![("expression", self.expression.to_json())]);
+    }
+}
+impl<'de> Jsonify for BlockStm<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "BlockStm",
+            vec![(
+                "statements",
+                json_array(self.statements.iter().map(|statement| statement.as_ref())),
+            )],
+        );
+    }
+}
+impl<'de> Jsonify for FuncBodyStm<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "FuncBodyStm",
+            vec![(
+                "statements",
+                json_array(self.statements.iter().map(|statement| statement.as_ref())),
+            )],
+        );
+    }
+}
+impl<'de> Jsonify for IfElseStm<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "IfElseStm",
+            vec![
+                ("condition", self.condition.to_json()),
+                ("then_branch", self.then_branch.to_json()),
+                ("else_branch", json_option(self.else_branch.as_deref())),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for WhileStm<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "WhileStm",
+            vec![
+                ("condition", self.condition.to_json()),
+                ("body", self.body.to_json()),
+            ],
+        );
+    }
+}
+impl<'de> Jsonify for ExpressionStm<'de> {
+    fn to_json(&self) -> String {
+        return self.object(
+            "ExpressionStm",
+            vec![("expression", self.expression.to_json())],
+        );
+    }
+}
+impl<'de> Jsonify for ReturnStm<'de> {
+    fn to_json(&self) -> String {
+        return self.object("ReturnStm", vec![("expression", self.expression.to_json())]);
+    }
+}
@@ -1,10 +1,14 @@
 pub(crate) mod debuge;
 pub(crate) mod declaration;
+pub(crate) mod dot;
 pub(crate) mod expression;
+pub(crate) mod jsonify;
 pub(crate) mod parser;
 pub(crate) mod statement;
 
 pub use debuge::Debuge;
+pub use dot::{DotGraph, Dotify};
+pub use jsonify::Jsonify;
 pub use parser::Parser;
 
 use declaration::*;
@@ -27,9 +31,13 @@ pub enum Node<'de> {
     UnaryExp(UnaryExp<'de>),
     BinaryExp(BinaryExp<'de>),
     LogicalExp(LogicalExp<'de>),
+    ConditionalExp(ConditionalExp<'de>),
+    CoalesceExp(CoalesceExp<'de>),
     AssignmentExp(AssignmentExp<'de>),
     CallExp(CallExp<'de>),
     GetExp(GetExp<'de>),
+    IndexExp(IndexExp<'de>),
+    InterpolationExp(InterpolationExp<'de>),
     SetExp(SetExp<'de>),
     ThisExp(ThisExp<'de>),
     SuperExp(SuperExp<'de>),
@@ -45,6 +53,30 @@ pub enum Node<'de> {
     ReturnStm(ReturnStm<'de>),
 }
 
+impl<'de> Node<'de> {
+    pub fn is_expression(&self) -> bool {
+        return matches!(
+            self,
+            Node::NilExp(_)
+                | Node::LiteralExp(_)
+                | Node::GroupingExp(_)
+                | Node::UnaryExp(_)
+                | Node::BinaryExp(_)
+                | Node::LogicalExp(_)
+                | Node::ConditionalExp(_)
+                | Node::CoalesceExp(_)
+                | Node::AssignmentExp(_)
+                | Node::CallExp(_)
+                | Node::GetExp(_)
+                | Node::IndexExp(_)
+                | Node::InterpolationExp(_)
+                | Node::SetExp(_)
+                | Node::ThisExp(_)
+                | Node::SuperExp(_)
+        );
+    }
+}
+
 impl<'de> Debuge for Node<'de> {
     #[rustfmt::skip]
     fn print(&self) -> String {
@@ -63,9 +95,13 @@ impl<'de> Debuge for Node<'de> {
             Node::UnaryExp(unary_exp) => unary_exp.print(),
             Node::BinaryExp(binary_exp) => binary_exp.print(),
             Node::LogicalExp(logical_exp) => logical_exp.print(),
+            Node::ConditionalExp(conditional_exp) => conditional_exp.print(),
+            Node::CoalesceExp(coalesce_exp) => coalesce_exp.print(),
             Node::AssignmentExp(assignment_exp) => assignment_exp.print(),
             Node::CallExp(call_exp) => call_exp.print(),
             Node::GetExp(get_exp) => get_exp.print(),
+            Node::IndexExp(index_exp) => index_exp.print(),
+            Node::InterpolationExp(interpolation_exp) => interpolation_exp.print(),
             Node::SetExp(set_exp) => set_exp.print(),
             Node::ThisExp(this_exp) => this_exp.print(),
             Node::SuperExp(super_exp) => super_exp.print(),
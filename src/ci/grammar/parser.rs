@@ -4,6 +4,9 @@ use crate::{Scanner, Token, TokenType};
 
 use super::{declaration::*, expression::*, statement::*, Node};
 
+/// Most parameters a function declares and arguments a call passes.
+const MAX_ARGUMENTS: usize = 255;
+
 macro_rules! ensure_consume_matches {
     ($scanner:expr, $( $pattern:pat ),* $(,)?) => {
         match $scanner.next().transpose() {
@@ -79,8 +82,52 @@ impl<'de> Parser<'de> {
         statements
     }
 
+    /// Parses the whole program, the final expression may omit its semicolon
+    /// and is then kept as a bare expression, so its value can be reported.
+    pub fn parse_program(mut self) -> Result<Vec<Box<Node<'de>>>, anyhow::Error> {
+        let mut statements = vec![];
+
+        while let Some(statement) = self.program_statement()? {
+            statements.push(statement);
+        }
+
+        return Ok(statements);
+    }
+
+    fn program_statement(&mut self) -> Result<Option<Box<Node<'de>>>, anyhow::Error> {
+        if peek_matches!(self.scanner, TokenType::EOF)? {
+            return Ok(None);
+        }
+
+        if peek_matches!(
+            self.scanner,
+            TokenType::CLASS
+                | TokenType::FUN
+                | TokenType::VAR
+                | TokenType::CONST
+                | TokenType::PRINT
+                | TokenType::LEFT_BRACE
+                | TokenType::IF
+                | TokenType::WHILE
+                | TokenType::FOR
+                | TokenType::RETURN
+        )? {
+            return self.parse().transpose();
+        }
+
+        let expression = self.expression()?;
+        if peek_matches!(self.scanner, TokenType::EOF)? {
+            return Ok(Some(expression));
+        }
+        let _ = ensure_consume_matches!(self.scanner, TokenType::SEMICOLON)?;
+
+        return Ok(Some(Box::new(Node::ExpressionStm(ExpressionStm {
+            expression,
+        }))));
+    }
+
     pub fn parse(&mut self) -> Option<Result<Box<Node<'de>>, anyhow::Error>> {
-        while let Some(token) = self.scanner.peek() {
+        if let Some(token) = self.scanner.peek() {
             match token
                 .as_ref()
                 .map_err(|e| anyhow::anyhow! { e.to_string() })
@@ -98,7 +145,7 @@ impl<'de> Parser<'de> {
                         return Some(self.fun_declaration());
                     }
 
-                    if matches!(token.token_type, TokenType::VAR) {
+                    if matches!(token.token_type, TokenType::VAR | TokenType::CONST) {
                         return Some(self.var_declaration());
                     }
 
@@ -171,7 +218,23 @@ impl<'de> Parser<'de> {
         let _ = ensure_consume_matches!(self.scanner, TokenType::LEFT_BRACE)?;
 
         let mut methods = vec![];
+        let mut final_fields = vec![];
         while !peek_matches!(self.scanner, TokenType::RIGHT_BRACE | TokenType::EOF)? {
+            if let Some(_) = consume_matches!(self.scanner, TokenType::FINAL) {
+                loop {
+                    let field = ensure_consume_matches!(self.scanner, TokenType::IDENTIFIER)?;
+                    final_fields.push(field);
+
+                    if let Some(_) = consume_matches!(self.scanner, TokenType::COMMA) {
+                    } else {
+                        break;
+                    }
+                }
+                let _ = ensure_consume_matches!(self.scanner, TokenType::SEMICOLON)?;
+
+                continue;
+            }
+
             let method = self.class_method_declaration()?;
             methods.push(method);
         }
@@ -182,30 +245,14 @@ impl<'de> Parser<'de> {
             name,
             super_class,
             methods,
+            final_fields,
         })));
     }
 
     fn class_method_declaration(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
         let name = ensure_consume_matches!(self.scanner, TokenType::IDENTIFIER)?;
 
-        let _ = ensure_consume_matches!(self.scanner, TokenType::LEFT_PAREN)?;
-        let mut parameters = vec![];
-        if !peek_matches!(self.scanner, TokenType::RIGHT_PAREN)? {
-            loop {
-                // TODO: Check max arguments count
-                // if args.len() >= 255 {
-                //     panic!()
-                // }
-                let parameter = ensure_consume_matches!(self.scanner, TokenType::IDENTIFIER)?;
-                parameters.push(parameter);
-
-                if let Some(_) = consume_matches!(self.scanner, TokenType::COMMA) {
-                } else {
-                    break;
-                }
-            }
-        }
-        let _ = ensure_consume_matches!(self.scanner, TokenType::RIGHT_PAREN)?;
+        let parameters = self.parameters()?;
 
         let body = self.func_body_statement()?;
         let body = Rc::from(body);
@@ -222,16 +269,60 @@ impl<'de> Parser<'de> {
 
         let name = ensure_consume_matches!(self.scanner, TokenType::IDENTIFIER)?;
 
+        let parameters = self.parameters()?;
+
+        let body = self.func_body_statement()?;
+        let body = Rc::from(body);
+
+        return Ok(Box::new(Node::FunctionDecl(FunctionDecl {
+            name,
+            parameters,
+            body,
+        })));
+    }
+
+    fn parameters(&mut self) -> Result<Vec<Parameter<'de>>, anyhow::Error> {
         let _ = ensure_consume_matches!(self.scanner, TokenType::LEFT_PAREN)?;
-        let mut parameters = vec![];
+        let mut parameters: Vec<Parameter> = vec![];
         if !peek_matches!(self.scanner, TokenType::RIGHT_PAREN)? {
             loop {
-                // TODO: Check max arguments count
-                // if args.len() >= 255 {
-                //     panic!()
-                // }
-                let parameter = ensure_consume_matches!(self.scanner, TokenType::IDENTIFIER)?;
-                parameters.push(parameter);
+                self.check_limit(parameters.len(), "parameters")?;
+                let rest = consume_matches!(self.scanner, TokenType::DOT_DOT_DOT).is_some();
+                let name = ensure_consume_matches!(self.scanner, TokenType::IDENTIFIER)?;
+
+                if parameters.last().is_some_and(|parameter| parameter.rest) {
+                    anyhow::bail! { Parser::error_at(
+                        name.line,
+                        name.lexeme,
+                        "Rest parameter must be the last one.",
+                    ) };
+                }
+                let default = if !rest && consume_matches!(self.scanner, TokenType::EQUAL).is_some()
+                {
+                    Some(Rc::from(self.expression()?))
+                } else {
+                    None
+                };
+
+                // Otherwise a required parameter could only be passed by name
+                if !rest
+                    && default.is_none()
+                    && parameters
+                        .iter()
+                        .any(|parameter| parameter.default.is_some())
+                {
+                    anyhow::bail! { Parser::error_at(
+                        name.line,
+                        name.lexeme,
+                        "A parameter without a default can't follow one with a default.",
+                    ) };
+                }
+
+                parameters.push(Parameter {
+                    name,
+                    default,
+                    rest,
+                });
 
                 if let Some(_) = consume_matches!(self.scanner, TokenType::COMMA) {
                 } else {
@@ -241,18 +332,12 @@ impl<'de> Parser<'de> {
         }
         let _ = ensure_consume_matches!(self.scanner, TokenType::RIGHT_PAREN)?;
 
-        let body = self.func_body_statement()?;
-        let body = Rc::from(body);
-
-        return Ok(Box::new(Node::FunctionDecl(FunctionDecl {
-            name,
-            parameters,
-            body,
-        })));
+        return Ok(parameters);
     }
 
     fn var_declaration(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
-        let _ = ensure_consume_matches!(self.scanner, TokenType::VAR)?;
+        let keyword = ensure_consume_matches!(self.scanner, TokenType::VAR | TokenType::CONST)?;
+        let constant = matches!(keyword.token_type, TokenType::CONST);
 
         let name = ensure_consume_matches!(self.scanner, TokenType::IDENTIFIER)?;
 
@@ -263,13 +348,24 @@ impl<'de> Parser<'de> {
             return Ok(Box::new(Node::VarDecl(VarDecl {
                 name,
                 initializer: Some(initializer),
+                constant,
             })));
         } else {
+            // A constant could never get a value later
+            if constant {
+                anyhow::bail! { Parser::error_at(
+                    name.line,
+                    name.lexeme,
+                    "Expect '=' after constant name.",
+                ) };
+            }
+
             let _ = ensure_consume_matches!(self.scanner, TokenType::SEMICOLON)?;
 
             return Ok(Box::new(Node::VarDecl(VarDecl {
                 name,
                 initializer: None,
+                constant,
             })));
         }
     }
@@ -374,7 +470,7 @@ impl<'de> Parser<'de> {
         let initializer = if let Some(_) = consume_matches!(self.scanner, TokenType::SEMICOLON) {
             Box::new(Node::EmptyStm(EmptyStm {}))
         } else {
-            if peek_matches!(self.scanner, TokenType::VAR)? {
+            if peek_matches!(self.scanner, TokenType::VAR | TokenType::CONST)? {
                 self.var_declaration()?
             } else {
                 self.expression_statement()?
@@ -447,31 +543,112 @@ impl<'de> Parser<'de> {
     }
 
     fn assignment(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
-        let expression = self.or()?;
+        let expression = self.conditional()?;
+
+        if !matches!(expression.as_ref(), Node::GetExp(_) | Node::LiteralExp(_)) {
+            return Ok(expression);
+        }
 
-        return match expression.as_ref() {
-            Node::GetExp(_) if peek_matches!(self.scanner, TokenType::EQUAL)? => {
-                // Ok. That is a property setter
-                let _ = ensure_consume_matches!(self.scanner, TokenType::EQUAL);
+        let Some(equal) = consume_matches!(
+            self.scanner,
+            TokenType::EQUAL
+                | TokenType::PLUS_EQUAL
+                | TokenType::MINUS_EQUAL
+                | TokenType::STAR_EQUAL
+                | TokenType::SLASH_EQUAL
+                | TokenType::PERCENT_EQUAL
+        ) else {
+            return Ok(expression);
+        };
+        if Self::is_optional_chain(&expression) {
+            anyhow::bail! { Parser::error_at(
+                equal.line,
+                equal.lexeme,
+                "Invalid assignment target. Can't assign through '?.'.",
+            ) }
+        }
+        let operator = Self::compound_operator(equal);
+        let value = self.assignment()?;
 
-                let value = self.assignment()?;
+        return match *expression {
+            // Ok. That is a property setter
+            Node::GetExp(_) => Ok(Box::new(Node::SetExp(SetExp {
+                get_exp: expression,
+                operator,
+                value,
+            }))),
+            // Ok. That is an variable assignment
+            Node::LiteralExp(literal) => Ok(Box::new(Node::AssignmentExp(AssignmentExp {
+                name: literal.name,
+                operator,
+                value,
+            }))),
+            _ => unreachable!(),
+        };
+    }
 
-                Ok(Box::new(Node::SetExp(SetExp {
-                    get_exp: expression,
-                    value,
-                })))
+    /// Whether any link of a call, property or index chain is `?.`.
+    fn is_optional_chain(node: &Node<'de>) -> bool {
+        return match node {
+            Node::GetExp(get_exp) => get_exp.optional || Self::is_optional_chain(&get_exp.callee),
+            Node::IndexExp(index_exp) => {
+                index_exp.optional || Self::is_optional_chain(&index_exp.object)
             }
-            Node::LiteralExp(literal) if peek_matches!(self.scanner, TokenType::EQUAL)? => {
-                // Ok. That is an variable assignment
-                let _ = ensure_consume_matches!(self.scanner, TokenType::EQUAL);
+            Node::CallExp(call_exp) => Self::is_optional_chain(&call_exp.callee),
+            _ => false,
+        };
+    }
 
-                let name = literal.name.clone();
-                let value = self.assignment()?;
+    /// The binary operator applied by a compound assignment, `+` for `+=`.
+    fn compound_operator(equal: Token<'de>) -> Option<Token<'de>> {
+        let token_type = match equal.token_type {
+            TokenType::PLUS_EQUAL => TokenType::PLUS,
+            TokenType::MINUS_EQUAL => TokenType::MINUS,
+            TokenType::STAR_EQUAL => TokenType::STAR,
+            TokenType::SLASH_EQUAL => TokenType::SLASH,
+            TokenType::PERCENT_EQUAL => TokenType::PERCENT,
+            _ => return None,
+        };
 
-                Ok(Box::new(Node::AssignmentExp(AssignmentExp { name, value })))
-            }
-            _ => Ok(expression),
+        return Some(Token {
+            token_type,
+            lexeme: &equal.lexeme[..1],
+            ..equal
+        });
+    }
+
+    // Right-associative, `a ? b : c ? d : e` is `a ? b : (c ? d : e)`
+    fn conditional(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
+        let condition = self.coalesce()?;
+
+        if consume_matches!(self.scanner, TokenType::QUESTION).is_none() {
+            return Ok(condition);
+        }
+
+        let then_branch = self.expression()?;
+        let _ = ensure_consume_matches!(self.scanner, TokenType::COLON)?;
+        let else_branch = self.assignment()?;
+
+        return Ok(Box::new(Node::ConditionalExp(ConditionalExp {
+            condition,
+            then_branch,
+            else_branch,
+        })));
+    }
+
+    fn coalesce(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
+        let left = self.or()?;
+
+        let Some(operator) = consume_matches!(self.scanner, TokenType::QUESTION_QUESTION) else {
+            return Ok(left);
         };
+        let right = self.coalesce()?;
+
+        return Ok(Box::new(Node::CoalesceExp(CoalesceExp {
+            left,
+            operator,
+            right,
+        })));
     }
 
     fn or(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
@@ -491,10 +668,10 @@ impl<'de> Parser<'de> {
     }
 
     fn and(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
-        let mut left = self.equality()?;
+        let mut left = self.bitwise_or()?;
 
         while let Some(operator) = consume_matches!(self.scanner, TokenType::AND) {
-            let right = self.equality()?;
+            let right = self.bitwise_or()?;
 
             left = Box::new(Node::LogicalExp(LogicalExp {
                 left,
@@ -506,6 +683,55 @@ impl<'de> Parser<'de> {
         return Ok(left);
     }
 
+    // Bitwise operators bind looser than equality, as in C
+    fn bitwise_or(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
+        let mut left = self.bitwise_xor()?;
+
+        while let Some(operator) = consume_matches!(self.scanner, TokenType::PIPE) {
+            let right = self.bitwise_xor()?;
+
+            left = Box::new(Node::BinaryExp(BinaryExp {
+                left,
+                operator,
+                right,
+            }));
+        }
+
+        return Ok(left);
+    }
+
+    fn bitwise_xor(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
+        let mut left = self.bitwise_and()?;
+
+        while let Some(operator) = consume_matches!(self.scanner, TokenType::CARET) {
+            let right = self.bitwise_and()?;
+
+            left = Box::new(Node::BinaryExp(BinaryExp {
+                left,
+                operator,
+                right,
+            }));
+        }
+
+        return Ok(left);
+    }
+
+    fn bitwise_and(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
+        let mut left = self.equality()?;
+
+        while let Some(operator) = consume_matches!(self.scanner, TokenType::AMPERSAND) {
+            let right = self.equality()?;
+
+            left = Box::new(Node::BinaryExp(BinaryExp {
+                left,
+                operator,
+                right,
+            }));
+        }
+
+        return Ok(left);
+    }
+
     fn equality(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
         let mut left = self.comparison()?;
 
@@ -525,11 +751,30 @@ impl<'de> Parser<'de> {
     }
 
     fn comparison(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
-        let mut left = self.term()?;
+        let mut left = self.shift()?;
 
         while let Some(operator) = consume_matches!(
             self.scanner,
             TokenType::GREATER | TokenType::GREATER_EQUAL | TokenType::LESS | TokenType::LESS_EQUAL
+        ) {
+            let right = self.shift()?;
+
+            left = Box::new(Node::BinaryExp(BinaryExp {
+                left,
+                operator,
+                right,
+            }));
+        }
+
+        return Ok(left);
+    }
+
+    fn shift(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
+        let mut left = self.term()?;
+
+        while let Some(operator) = consume_matches!(
+            self.scanner,
+            TokenType::LESS_LESS | TokenType::GREATER_GREATER
         ) {
             let right = self.term()?;
 
@@ -564,9 +809,10 @@ impl<'de> Parser<'de> {
     fn factor(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
         let mut left = self.unary()?;
 
-        while let Some(operator) =
-            consume_matches!(self.scanner, TokenType::STAR | TokenType::SLASH)
-        {
+        while let Some(operator) = consume_matches!(
+            self.scanner,
+            TokenType::STAR | TokenType::SLASH | TokenType::TILDE_SLASH | TokenType::PERCENT
+        ) {
             let right = self.unary()?;
 
             left = Box::new(Node::BinaryExp(BinaryExp {
@@ -584,19 +830,45 @@ impl<'de> Parser<'de> {
             anyhow::bail! {"Unexpected EOF"};
         }
 
-        if let Some(operator) = consume_matches!(self.scanner, TokenType::BANG | TokenType::MINUS) {
+        if let Some(operator) = consume_matches!(
+            self.scanner,
+            TokenType::BANG | TokenType::MINUS | TokenType::TILDE
+        ) {
             let right = self.unary()?;
 
             return Ok(Box::new(Node::UnaryExp(UnaryExp { operator, right })));
         } else {
-            return self.call();
+            return self.exponent();
         }
     }
 
+    // Right-associative and binds tighter than unary operators on its left: -2 ** 2 is -4
+    fn exponent(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
+        let left = self.call()?;
+
+        if let Some(operator) = consume_matches!(self.scanner, TokenType::STAR_STAR) {
+            let right = self.unary()?;
+
+            return Ok(Box::new(Node::BinaryExp(BinaryExp {
+                left,
+                operator,
+                right,
+            })));
+        }
+
+        return Ok(left);
+    }
+
     fn call(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
         let mut callee = self.primary()?;
 
-        while peek_matches!(self.scanner, TokenType::LEFT_PAREN | TokenType::DOT)? {
+        while peek_matches!(
+            self.scanner,
+            TokenType::LEFT_PAREN
+                | TokenType::DOT
+                | TokenType::QUESTION_DOT
+                | TokenType::LEFT_BRACKET
+        )? {
             if peek_matches!(self.scanner, TokenType::LEFT_PAREN)? {
                 let _ = ensure_consume_matches!(self.scanner, TokenType::LEFT_PAREN)?;
                 callee = self.call_func(callee)?;
@@ -605,7 +877,22 @@ impl<'de> Parser<'de> {
 
             if peek_matches!(self.scanner, TokenType::DOT)? {
                 let _ = ensure_consume_matches!(self.scanner, TokenType::DOT)?;
-                callee = self.get_property(callee)?;
+                callee = self.get_property(callee, false)?;
+            }
+
+            if peek_matches!(self.scanner, TokenType::QUESTION_DOT)? {
+                let _ = ensure_consume_matches!(self.scanner, TokenType::QUESTION_DOT)?;
+                // `a?.[i]` indexes, anything else is a property
+                callee = if consume_matches!(self.scanner, TokenType::LEFT_BRACKET).is_some() {
+                    self.get_index(callee, true)?
+                } else {
+                    self.get_property(callee, true)?
+                };
+            }
+
+            if peek_matches!(self.scanner, TokenType::LEFT_BRACKET)? {
+                let _ = ensure_consume_matches!(self.scanner, TokenType::LEFT_BRACKET)?;
+                callee = self.get_index(callee, false)?;
             }
         }
 
@@ -614,13 +901,45 @@ impl<'de> Parser<'de> {
 
     fn call_func(&mut self, callee: Box<Node<'de>>) -> Result<Box<Node<'de>>, anyhow::Error> {
         let mut args = vec![];
+        let mut named_args = vec![];
         if !peek_matches!(self.scanner, TokenType::RIGHT_PAREN)? {
             loop {
-                // TODO: Check max arguments count
-                // if args.len() >= 255 {
-                //     panic!()
-                // }
-                args.push(self.expression()?);
+                self.check_limit(args.len() + named_args.len(), "arguments")?;
+                let start = self
+                    .scanner
+                    .peek()
+                    .and_then(|token| token.as_ref().ok())
+                    .map(|token| (token.line, token.lexeme));
+                let arg = self.expression()?;
+
+                // A lone identifier followed by `:` names the parameter it is passed to
+                let name = match *arg {
+                    Node::LiteralExp(LiteralExp {
+                        name:
+                            ref name @ Token {
+                                token_type: TokenType::IDENTIFIER,
+                                ..
+                            },
+                    }) if peek_matches!(self.scanner, TokenType::COLON)? => Some(name.clone()),
+                    _ => None,
+                };
+
+                if let Some(name) = name {
+                    let _ = ensure_consume_matches!(self.scanner, TokenType::COLON)?;
+                    let value = self.expression()?;
+
+                    named_args.push(NamedArg { name, value });
+                } else if !named_args.is_empty() {
+                    let (line, lexeme) = start.expect("The argument was parsed from this token");
+                    anyhow::bail! { Parser::error_at(
+                        line,
+                        lexeme,
+                        "Positional arguments can't follow named ones.",
+                    ) };
+                } else {
+                    args.push(arg);
+                }
+
                 if let Some(_) = consume_matches!(self.scanner, TokenType::COMMA) {
                 } else {
                     break;
@@ -628,17 +947,47 @@ impl<'de> Parser<'de> {
             }
         }
 
-        return Ok(Box::new(Node::CallExp(CallExp { callee, args })));
+        return Ok(Box::new(Node::CallExp(CallExp {
+            callee,
+            args,
+            named_args,
+        })));
     }
 
-    fn get_property(&mut self, callee: Box<Node<'de>>) -> Result<Box<Node<'de>>, anyhow::Error> {
+    fn get_property(
+        &mut self,
+        callee: Box<Node<'de>>,
+        optional: bool,
+    ) -> Result<Box<Node<'de>>, anyhow::Error> {
         if let Some(name) = consume_matches!(self.scanner, TokenType::IDENTIFIER) {
-            return Ok(Box::new(Node::GetExp(GetExp { callee, name })));
+            return Ok(Box::new(Node::GetExp(GetExp {
+                callee,
+                name,
+                optional,
+            })));
+        } else if optional {
+            anyhow::bail! {"Expect property name or '[' after '?.'."}
         } else {
             anyhow::bail! {"Expect property name after '.'."}
         }
     }
 
+    // The opening `[` is already consumed
+    fn get_index(
+        &mut self,
+        object: Box<Node<'de>>,
+        optional: bool,
+    ) -> Result<Box<Node<'de>>, anyhow::Error> {
+        let index = self.expression()?;
+        let _ = ensure_consume_matches!(self.scanner, TokenType::RIGHT_BRACKET)?;
+
+        return Ok(Box::new(Node::IndexExp(IndexExp {
+            object,
+            index,
+            optional,
+        })));
+    }
+
     fn primary(&mut self) -> Result<Box<Node<'de>>, anyhow::Error> {
         if peek_matches!(self.scanner, TokenType::EOF)? {
             anyhow::bail! {"Unexpected EOF"};
@@ -651,10 +1000,34 @@ impl<'de> Parser<'de> {
                 | TokenType::NIL
                 | TokenType::STRING(_)
                 | TokenType::NUMBER(_)
+                | TokenType::INTEGER(_)
+                | TokenType::BIG_INTEGER(_)
         ) {
             return Ok(Box::new(Node::LiteralExp(LiteralExp { name })));
         }
 
+        if let Some(name) = consume_matches!(self.scanner, TokenType::INTERPOLATION(_)) {
+            let mut parts = vec![Box::new(Node::LiteralExp(LiteralExp { name }))];
+            loop {
+                parts.push(self.expression()?);
+
+                // The string goes on until a segment without an embedded expression
+                let name = ensure_consume_matches!(
+                    self.scanner,
+                    TokenType::INTERPOLATION(_),
+                    TokenType::STRING(_)
+                )?;
+                let last = matches!(name.token_type, TokenType::STRING(_));
+                parts.push(Box::new(Node::LiteralExp(LiteralExp { name })));
+
+                if last {
+                    break;
+                }
+            }
+
+            return Ok(Box::new(Node::InterpolationExp(InterpolationExp { parts })));
+        }
+
         if let Some(token) = consume_matches!(self.scanner, TokenType::THIS) {
             return Ok(Box::new(Node::ThisExp(ThisExp { token })));
         }
@@ -698,8 +1071,26 @@ impl<'de> Parser<'de> {
         anyhow::bail! { Parser::error_at(token.line, token.lexeme, "Expect expression.") };
     }
 
+    /// Fails at the next token when a list already holds `count` of the allowed items.
+    fn check_limit(&mut self, count: usize, items: &str) -> Result<(), anyhow::Error> {
+        if count < MAX_ARGUMENTS {
+            return Ok(());
+        }
+
+        // A scanner error is reported by whatever parses the token
+        if let Some(Ok(token)) = self.scanner.peek() {
+            anyhow::bail! { Parser::error_at(
+                token.line,
+                token.lexeme,
+                &format!("Can't have more than {MAX_ARGUMENTS} {items}."),
+            ) };
+        }
+
+        return Ok(());
+    }
+
     fn error_at(line: usize, lexeme: &str, message: &str) -> String {
-        format!("[line {line}] Error at '{lexeme}': {message}'")
+        format!("[line {line}] Error at '{lexeme}': {message}")
     }
 }
 
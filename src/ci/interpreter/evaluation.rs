@@ -4,14 +4,21 @@ use std::rc::Rc;
 use crate::ci::class::ClassMethod;
 use crate::ci::Function;
 use crate::ci::{Class, ClassInstance};
+use crate::BigInt;
 
 #[derive(Clone)]
 pub enum Evaluation<'de> {
     Number(f64),
+    Integer(i64),
+    // Only integers that don't fit in an `Integer`
+    BigInteger(BigInt),
     Boolean(bool),
     // TODO: Staric storage for string literals
     // TODO: Remove owning
     String(String),
+    List(Rc<RefCell<Vec<Evaluation<'de>>>>),
+    // Entries are kept in insertion order
+    Map(Rc<RefCell<Vec<(String, Evaluation<'de>)>>>),
     Nil,
     Fn(Rc<Function<'de>>),
     Class(Rc<Class<'de>>),
@@ -20,12 +27,275 @@ pub enum Evaluation<'de> {
     None,
 }
 
+/// Largest amount accepted by `<<` and `>>`, the shifts compute `2 ** amount` with `BigInt::pow`.
+const MAX_SHIFT: u32 = BigInt::MAX_POW_BITS;
+
+/// Operands of an arithmetic operation: two integers stay integers, big ones if either is big,
+/// as soon as one of them is a float both are used as floats.
+pub(crate) enum Operands {
+    Integers(i64, i64),
+    BigIntegers(BigInt, BigInt),
+    Floats(f64, f64),
+}
+
+impl Operands {
+    pub(crate) fn of(left: &Evaluation<'_>, right: &Evaluation<'_>) -> Option<Self> {
+        return match (left, right) {
+            (Evaluation::Integer(left), Evaluation::Integer(right)) => {
+                Some(Operands::Integers(*left, *right))
+            }
+            (
+                Evaluation::Integer(_) | Evaluation::BigInteger(_),
+                Evaluation::Integer(_) | Evaluation::BigInteger(_),
+            ) => Some(Operands::BigIntegers(
+                left.as_big_integer()?,
+                right.as_big_integer()?,
+            )),
+            _ => Some(Operands::Floats(left.as_number()?, right.as_number()?)),
+        };
+    }
+
+    /// Operands of `&`, `|` and `^`, which only work on 64 bits integers.
+    /// Floats are refused rather than truncated silently.
+    pub(crate) fn bitwise(
+        operator: &str,
+        left: &Evaluation<'_>,
+        right: &Evaluation<'_>,
+    ) -> anyhow::Result<(i64, i64)> {
+        let operand = |value: &Evaluation<'_>| match value {
+            Evaluation::Integer(n) => Ok(*n),
+            Evaluation::BigInteger(_) => {
+                anyhow::bail! {"Operands of '{operator}' must fit in 64 bits."}
+            }
+            _ => anyhow::bail! {
+                "Operands of '{operator}' must be integers but got {}.", value.describe()
+            },
+        };
+
+        return Ok((operand(left)?, operand(right)?));
+    }
+}
+
+impl<'de> Evaluation<'de> {
+    /// Integer result of an operation, computed again on big integers if the operation overflowed.
+    pub fn integer_or(integer: Option<i64>, big_integer: impl FnOnce() -> BigInt) -> Self {
+        integer.map_or_else(|| Evaluation::integer(big_integer()), Evaluation::Integer)
+    }
+
+    /// Smallest representation of an integer, big integers only when 64 bits aren't enough.
+    pub fn integer(value: BigInt) -> Self {
+        match value.to_i64() {
+            Some(value) => Evaluation::Integer(value),
+            None => Evaluation::BigInteger(value),
+        }
+    }
+
+    pub fn as_number(&self) -> Option<f64> {
+        match self {
+            Evaluation::Number(n) => Some(*n),
+            Evaluation::Integer(n) => Some(*n as f64),
+            Evaluation::BigInteger(n) => Some(n.to_f64()),
+            _ => None,
+        }
+    }
+
+    pub fn as_big_integer(&self) -> Option<BigInt> {
+        match self {
+            Evaluation::Integer(n) => Some(BigInt::from(*n)),
+            Evaluation::BigInteger(n) => Some(n.clone()),
+            _ => None,
+        }
+    }
+
+    /// `self ** exponent`, exact for integers raised to a non-negative integer power.
+    /// `None` when an operand isn't a number, an error when the exact result is too large.
+    pub(crate) fn pow(&self, exponent: &Evaluation<'de>) -> anyhow::Result<Option<Self>> {
+        if let (Some(base), Evaluation::Integer(exponent)) = (self.as_big_integer(), exponent) {
+            if let Ok(exponent) = u32::try_from(*exponent) {
+                if let Evaluation::Integer(base) = self {
+                    if let Some(result) = base.checked_pow(exponent) {
+                        return Ok(Some(Evaluation::Integer(result)));
+                    }
+                }
+
+                let Some(result) = base.pow(exponent) else {
+                    anyhow::bail! {
+                        "Exponent {exponent} is too large, results are limited to {} bits.",
+                        BigInt::MAX_POW_BITS
+                    };
+                };
+
+                return Ok(Some(Evaluation::integer(result)));
+            }
+        }
+
+        let (Some(base), Some(exponent)) = (self.as_number(), exponent.as_number()) else {
+            return Ok(None);
+        };
+
+        return Ok(Some(Evaluation::Number(base.powf(exponent))));
+    }
+
+    /// `self << amount`, continued on big integers when bits are shifted out of 64 bits.
+    pub(crate) fn shift_left(&self, amount: &Evaluation<'de>) -> anyhow::Result<Self> {
+        let amount = Self::shift_amount("<<", self, amount)?;
+
+        if let Evaluation::Integer(n) = self {
+            if amount < 64 && (n << amount) >> amount == *n {
+                return Ok(Evaluation::Integer(n << amount));
+            }
+        }
+
+        let Some(n) = self.as_big_integer() else {
+            unreachable!("shift_amount only accepts integers");
+        };
+
+        let power = BigInt::from(2)
+            .pow(amount)
+            .expect("Shift amounts are within the pow limit");
+
+        return Ok(Evaluation::integer(&n * &power));
+    }
+
+    /// `self >> amount`, an arithmetic shift that rounds towards negative infinity.
+    pub(crate) fn shift_right(&self, amount: &Evaluation<'de>) -> anyhow::Result<Self> {
+        let amount = Self::shift_amount(">>", self, amount)?;
+
+        return Ok(match self {
+            Evaluation::Integer(n) => Evaluation::Integer(n >> amount.min(63)),
+            Evaluation::BigInteger(n) => {
+                let power = BigInt::from(2)
+                    .pow(amount)
+                    .expect("Shift amounts are within the pow limit");
+                let Some((quotient, remainder)) = n.div_rem(&power) else {
+                    unreachable!("a power of two is never zero");
+                };
+
+                // The quotient is truncated towards zero, negative values round down instead
+                if remainder < BigInt::from(0) {
+                    Evaluation::integer(&quotient - &BigInt::from(1))
+                } else {
+                    Evaluation::integer(quotient)
+                }
+            }
+            _ => unreachable!("shift_amount only accepts integers"),
+        });
+    }
+
+    fn shift_amount(
+        operator: &str,
+        value: &Evaluation<'de>,
+        amount: &Evaluation<'de>,
+    ) -> anyhow::Result<u32> {
+        for operand in [value, amount] {
+            if !matches!(operand, Evaluation::Integer(_) | Evaluation::BigInteger(_)) {
+                anyhow::bail! {
+                    "Operands of '{operator}' must be integers but got {}.", operand.describe()
+                };
+            }
+        }
+
+        return match amount {
+            // Bigger shifts would take ages to compute on big integers
+            Evaluation::Integer(n) if (0..=MAX_SHIFT as i64).contains(n) => Ok(*n as u32),
+            _ if amount.as_big_integer().is_some_and(|n| n < BigInt::from(0)) => {
+                anyhow::bail! {"Shift amount must not be negative but got {amount}."}
+            }
+            _ => anyhow::bail! {"Shift amount {amount} is too large, the limit is {MAX_SHIFT}."},
+        };
+    }
+
+    /// Numbers are shown with their value, floats always with a fraction,
+    /// anything else by its type.
+    pub(crate) fn describe(&self) -> String {
+        match self {
+            Evaluation::Number(n) => format!("{n:?}"),
+            Evaluation::Integer(_) | Evaluation::BigInteger(_) => self.to_string(),
+            _ => self.type_name().to_string(),
+        }
+    }
+
+    pub fn list(items: Vec<Evaluation<'de>>) -> Self {
+        Evaluation::List(Rc::new(RefCell::new(items)))
+    }
+
+    pub fn map(entries: Vec<(String, Evaluation<'de>)>) -> Self {
+        Evaluation::Map(Rc::new(RefCell::new(entries)))
+    }
+
+    pub(crate) fn get_index(&self, index: &Evaluation<'de>) -> anyhow::Result<Evaluation<'de>> {
+        if let Evaluation::Map(m) = self {
+            let Evaluation::String(key) = index else {
+                anyhow::bail! {"Map key must be a string but got {}.", index.type_name()}
+            };
+
+            return m
+                .borrow()
+                .iter()
+                .find(|(name, _)| name == key)
+                .map(|(_, value)| value.clone())
+                .ok_or(anyhow::anyhow! {"Undefined key '{key}'."});
+        }
+
+        let position = match index {
+            Evaluation::Integer(n) if *n >= 0 => *n as usize,
+            Evaluation::Integer(n) => {
+                anyhow::bail! {"Index must be a non-negative integer but got {n}."}
+            }
+            Evaluation::Number(n) if n.fract() == 0.0 && *n >= 0.0 => *n as usize,
+            Evaluation::Number(n) => {
+                anyhow::bail! {"Index must be a non-negative integer but got {n}."}
+            }
+            _ => anyhow::bail! {"Index must be a number but got {}.", index.type_name()},
+        };
+
+        let (item, len) = match self {
+            Evaluation::String(s) => (
+                s.chars()
+                    .nth(position)
+                    .map(|c| Evaluation::String(c.to_string())),
+                s.chars().count(),
+            ),
+            Evaluation::List(l) => {
+                let l = l.borrow();
+                (l.get(position).cloned(), l.len())
+            }
+            _ => {
+                anyhow::bail! {"Only strings, lists and maps can be indexed but got {}.", self.type_name()}
+            }
+        };
+
+        item.ok_or(anyhow::anyhow! {
+            "Index {position} out of bounds for {} of length {len}.",
+            self.type_name()
+        })
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Evaluation::Number(_) | Evaluation::Integer(_) | Evaluation::BigInteger(_) => "number",
+            Evaluation::Boolean(_) => "boolean",
+            Evaluation::String(_) => "string",
+            Evaluation::List(_) => "list",
+            Evaluation::Map(_) => "map",
+            Evaluation::Nil | Evaluation::None => "nil",
+            Evaluation::Fn(_) | Evaluation::ClassMethod(_) => "function",
+            Evaluation::Class(_) => "class",
+            Evaluation::ClassInstance(_) => "instance",
+        }
+    }
+}
+
 impl std::fmt::Debug for Evaluation<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Number(arg0) => f.debug_tuple("Number").field(arg0).finish(),
+            Self::Integer(arg0) => f.debug_tuple("Integer").field(arg0).finish(),
+            Self::BigInteger(arg0) => write!(f, "BigInteger({arg0})"),
             Self::Boolean(arg0) => f.debug_tuple("Boolean").field(arg0).finish(),
             Self::String(arg0) => f.debug_tuple("String").field(arg0).finish(),
+            Self::List(arg0) => f.debug_tuple("List").field(&arg0.borrow()).finish(),
+            Self::Map(arg0) => f.debug_tuple("Map").field(&arg0.borrow()).finish(),
             Self::Nil => write!(f, "Nil"),
             Self::Fn(arg0) => write!(f, "Fn: {}", arg0.name(),),
             Self::Class(arg0) => write!(f, "Class: {}", arg0.name),
@@ -40,10 +310,32 @@ impl std::fmt::Display for Evaluation<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match &self {
             Evaluation::Number(n) => write!(f, "{n}"),
+            Evaluation::Integer(n) => write!(f, "{n}"),
+            Evaluation::BigInteger(n) => write!(f, "{n}"),
             Evaluation::Boolean(true) => write!(f, "true"),
             Evaluation::Boolean(false) => write!(f, "false"),
             Evaluation::Nil => write!(f, "nil"),
             Evaluation::String(s) => write!(f, "{s}"),
+            Evaluation::List(l) => {
+                write!(f, "[")?;
+                for (i, item) in l.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+            Evaluation::Map(m) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in m.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{key}: {value}")?;
+                }
+                write!(f, "}}")
+            }
             Evaluation::Fn(s) => write!(f, "<fn {}>", s.name()),
             Evaluation::Class(c) => write!(f, "{}", c.name,),
             Evaluation::ClassMethod(m) => write!(f, "<method {}>", m.func.name()),
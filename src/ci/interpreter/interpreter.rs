@@ -4,17 +4,17 @@ use crate::{
     ci::{
         class::{ClassMethod, CLASS_STR, THIS_STR},
         environment::persist_environment,
-        function::{custom::CustomFunction, Callable},
+        function::{check_arity, custom::CustomFunction, Callable},
         Class, ClassInstance, Environment, Function, Node,
     },
-    get_environment_value_typed, TokenType,
+    get_environment_value_typed, BigInt, Token, TokenType,
 };
 
 use crate::ci::grammar::declaration::*;
 use crate::ci::grammar::expression::*;
 use crate::ci::grammar::statement::*;
 
-use super::{Evaluation, Interruption};
+use super::{evaluation::Operands, Evaluation, Interruption, RuntimeOptions};
 
 pub struct Interpreter<'de> {
     global_environment: Rc<RefCell<Environment<'de>>>,
@@ -23,15 +23,23 @@ pub struct Interpreter<'de> {
 
 impl<'de> Interpreter<'de> {
     pub fn new(statements: impl IntoIterator<Item = Box<Node<'de>>>) -> Self {
+        Self::with_options(statements, RuntimeOptions::default())
+    }
+
+    pub fn with_options(
+        statements: impl IntoIterator<Item = Box<Node<'de>>>,
+        options: RuntimeOptions,
+    ) -> Self {
         Self {
             statements: statements.into_iter().collect(),
-            global_environment: Rc::new(RefCell::new(Environment::root())),
+            global_environment: Rc::new(RefCell::new(Environment::root(&options))),
         }
     }
     pub fn run(&mut self) {
         for statement in self.statements.iter() {
             match statement.interpret(self.global_environment.clone()) {
                 Ok(_) => {}
+                Err(Interruption::Exit(code)) => exit(code),
                 Err(error) => {
                     eprintln!("{error}");
                     exit(70);
@@ -39,6 +47,37 @@ impl<'de> Interpreter<'de> {
             }
         }
     }
+
+    /// Runs the statements and returns the value of the final one if it's an expression,
+    /// with or without its semicolon.
+    pub fn evaluate(&mut self) -> Option<Evaluation<'de>> {
+        let mut value = None;
+        for statement in self.statements.iter() {
+            let result = match statement.as_ref() {
+                Node::ExpressionStm(expression_stm) => expression_stm
+                    .expression
+                    .interpret(self.global_environment.clone())
+                    .map(Some),
+                node if node.is_expression() => {
+                    node.interpret(self.global_environment.clone()).map(Some)
+                }
+                node => node
+                    .interpret(self.global_environment.clone())
+                    .map(|_| None),
+            };
+
+            match result {
+                Ok(evaluation) => value = evaluation,
+                Err(Interruption::Exit(code)) => exit(code),
+                Err(error) => {
+                    eprintln!("{error}");
+                    exit(70);
+                }
+            }
+        }
+
+        return value;
+    }
 }
 
 pub trait Interpret<'env> {
@@ -58,7 +97,11 @@ impl<'de> Interpret<'de> for VarDecl<'de> {
             let initialization = initializer.interpret(environment.clone())?;
 
             let mut environment_ = environment.borrow_mut();
-            environment_.define(self.name.lexeme, initialization);
+            if self.constant {
+                environment_.define_constant(self.name.lexeme, initialization);
+            } else {
+                environment_.define(self.name.lexeme, initialization);
+            }
         } else {
             let mut environment_ = environment.borrow_mut();
             environment_.define(self.name.lexeme, Evaluation::Nil);
@@ -77,8 +120,7 @@ impl<'de> Interpret<'de> for FunctionDecl<'de> {
         let body = self.body.clone();
         let func = Rc::new(Function::CustomFunction(Box::new(CustomFunction {
             parameters: self.parameters.clone(),
-            name: &self.name.lexeme,
-            arity: self.parameters.len(),
+            name: self.name.lexeme,
             captured_environment,
             body,
         })));
@@ -100,8 +142,7 @@ impl<'de> Interpret<'de> for ClassMethodDecl<'de> {
         let body = self.body.clone();
         let func = Rc::new(Function::CustomFunction(Box::new(CustomFunction {
             parameters: self.parameters.clone(),
-            name: &self.name.lexeme,
-            arity: self.parameters.len(),
+            name: self.name.lexeme,
             captured_environment: environment.clone(),
             body,
         })));
@@ -151,6 +192,7 @@ impl<'de> Interpret<'de> for ClassDecl<'de> {
             name: self.name.lexeme,
             super_class,
             class_environment: captured_environment.clone(),
+            final_fields: self.final_fields.iter().map(|field| field.lexeme).collect(),
         }));
 
         {
@@ -221,10 +263,14 @@ impl<'de> Interpret<'de> for LiteralExp<'de> {
     ) -> Result<Evaluation<'de>, Interruption<'de>> {
         return match self.name.token_type {
             TokenType::NUMBER(n) => Ok(Evaluation::Number(n)),
+            TokenType::INTEGER(n) => Ok(Evaluation::Integer(n)),
+            TokenType::BIG_INTEGER(ref n) => Ok(Evaluation::BigInteger(n.clone())),
             TokenType::TRUE => Ok(Evaluation::Boolean(true)),
             TokenType::FALSE => Ok(Evaluation::Boolean(false)),
             TokenType::NIL => Ok(Evaluation::Nil),
-            TokenType::STRING(ref s) => Ok(Evaluation::String(s.clone())),
+            TokenType::STRING(ref s) | TokenType::INTERPOLATION(ref s) => {
+                Ok(Evaluation::String(s.clone()))
+            }
             TokenType::IDENTIFIER => {
                 let name = &self.name.lexeme;
                 let environment_ = environment.borrow();
@@ -262,7 +308,9 @@ impl<'de> Interpret<'de> for UnaryExp<'de> {
             TokenType::BANG => match self.right.interpret(environment)? {
                 Evaluation::Boolean(b) => Ok(Evaluation::Boolean(!b)),
                 Evaluation::Nil => Ok(Evaluation::Boolean(true)),
-                Evaluation::Number(_) => Ok(Evaluation::Boolean(false)),
+                Evaluation::Number(_) | Evaluation::Integer(_) | Evaluation::BigInteger(_) => {
+                    Ok(Evaluation::Boolean(false))
+                }
                 e => {
                     return Err(Interruption::Error(
                         anyhow::anyhow! {"Unsupported unary BANG for evaluation: {e}"},
@@ -270,13 +318,28 @@ impl<'de> Interpret<'de> for UnaryExp<'de> {
                 }
             },
             TokenType::MINUS => match self.right.interpret(environment)? {
-                Evaluation::Number(n) => Ok(Evaluation::Number(-1.0 * n)),
+                Evaluation::Number(n) => Ok(Evaluation::Number(-n)),
+                // Only the smallest integer has no 64 bits negation
+                Evaluation::Integer(n) => Ok(Evaluation::integer_or(n.checked_neg(), || {
+                    -&BigInt::from(n)
+                })),
+                Evaluation::BigInteger(n) => Ok(Evaluation::integer(-&n)),
                 e => {
                     return Err(Interruption::Error(
                         anyhow::anyhow! {"Unsupported unary MINUS for evaluation: {e}"},
                     ))
                 }
             },
+            TokenType::TILDE => match self.right.interpret(environment)? {
+                Evaluation::Integer(n) => Ok(Evaluation::Integer(!n)),
+                // Two's complement, as if big integers had infinitely many sign bits
+                Evaluation::BigInteger(n) => Ok(Evaluation::integer(&-&n - &BigInt::from(1))),
+                e => {
+                    return Err(Interruption::Error(anyhow::anyhow! {
+                        "Operand of '~' must be an integer but got {}.", e.describe()
+                    }))
+                }
+            },
             _ => {
                 return Err(Interruption::Error(
                     anyhow::anyhow! {"Unsupported unary operator: {o}", o = &self.operator},
@@ -293,121 +356,229 @@ impl<'de> Interpret<'de> for BinaryExp<'de> {
         let left = self.left.interpret(environment.clone())?;
         let right = self.right.interpret(environment.clone())?;
 
-        match self.operator.token_type {
-            TokenType::PLUS => match (left, right) {
-                (Evaluation::Number(left), Evaluation::Number(right)) => {
-                    Ok(Evaluation::Number(left + right))
-                }
-                (Evaluation::String(left), Evaluation::String(right)) => {
-                    Ok(Evaluation::String(format!("{left}{right}")))
-                }
-                e => {
-                    return Err(Interruption::Error(
-                        anyhow::anyhow! {"Unsupported binary PLUS for evaluation: {e:?}"},
-                    ))
-                }
-            },
-            TokenType::MINUS => match (left, right) {
-                (Evaluation::Number(left), Evaluation::Number(right)) => {
-                    Ok(Evaluation::Number(left - right))
-                }
-                e @ _ => {
-                    return Err(Interruption::Error(
-                        anyhow::anyhow! {"Unsupported binary MINUS for evaluation: {e:?}"},
-                    ))
-                }
-            },
-            TokenType::STAR => match (left, right) {
-                (Evaluation::Number(left), Evaluation::Number(right)) => {
-                    Ok(Evaluation::Number(left * right))
-                }
-                e @ _ => {
-                    return Err(Interruption::Error(
-                        anyhow::anyhow! {"Unsupported binary STAR for evaluation: {e:?}"},
-                    ))
-                }
-            },
-            TokenType::SLASH => match (left, right) {
-                (Evaluation::Number(left), Evaluation::Number(right)) => {
-                    Ok(Evaluation::Number(left / right))
-                }
-                e @ _ => {
-                    return Err(Interruption::Error(
-                        anyhow::anyhow! {"Unsupported binary SLASH for evaluation: {e:?}"},
-                    ))
-                }
-            },
-            TokenType::LESS => match (left, right) {
-                (Evaluation::Number(left), Evaluation::Number(right)) => {
-                    Ok(Evaluation::Boolean(left < right))
-                }
-                e @ _ => {
-                    return Err(Interruption::Error(
-                        anyhow::anyhow! {"Unsupported binary LESS for evaluation: {e:?}"},
-                    ))
-                }
-            },
-            TokenType::LESS_EQUAL => match (left, right) {
-                (Evaluation::Number(left), Evaluation::Number(right)) => {
-                    Ok(Evaluation::Boolean(left <= right))
-                }
-                e @ _ => {
-                    return Err(Interruption::Error(
-                        anyhow::anyhow! {"Unsupported binary LESS_EQUAL for evaluation: {e:?}"},
-                    ))
-                }
-            },
-            TokenType::GREATER => match (left, right) {
-                (Evaluation::Number(left), Evaluation::Number(right)) => {
-                    Ok(Evaluation::Boolean(left > right))
-                }
-                e @ _ => {
-                    return Err(Interruption::Error(
-                        anyhow::anyhow! {"Unsupported binary GREATER for evaluation: {e:?}"},
-                    ))
-                }
-            },
-            TokenType::GREATER_EQUAL => match (left, right) {
-                (Evaluation::Number(left), Evaluation::Number(right)) => {
-                    Ok(Evaluation::Boolean(left >= right))
-                }
-                e @ _ => {
-                    return Err(Interruption::Error(
-                        anyhow::anyhow! {"Unsupported binary GREATER_EQUAL for evaluation: {e:?}"},
-                    ));
-                }
-            },
-            TokenType::EQUAL_EQUAL => match (left, right) {
-                (Evaluation::Number(left), Evaluation::Number(right)) => {
-                    Ok(Evaluation::Boolean(left == right))
-                }
-                (Evaluation::String(left), Evaluation::String(right)) => {
-                    Ok(Evaluation::Boolean(left == right))
-                }
-                (Evaluation::Boolean(left), Evaluation::Boolean(right)) => {
-                    Ok(Evaluation::Boolean(left == right))
-                }
-                (Evaluation::Nil, Evaluation::Nil) => Ok(Evaluation::Boolean(true)),
-                _ => Ok(Evaluation::Boolean(false)),
+        return binary_operation(&self.operator, left, right);
+    }
+}
+/// Arithmetic between two integers gives an integer, continued on big integers if it overflows.
+/// As soon as a float is involved the result is a float. `/` always divides exactly and gives
+/// a float, `~/` truncates the quotient towards zero like `%` does for the remainder.
+fn binary_operation<'de>(
+    operator: &Token<'de>,
+    left: Evaluation<'de>,
+    right: Evaluation<'de>,
+) -> Result<Evaluation<'de>, Interruption<'de>> {
+    match operator.token_type {
+        TokenType::PLUS => match (Operands::of(&left, &right), left, right) {
+            (Some(Operands::Integers(left, right)), ..) => {
+                Ok(Evaluation::integer_or(left.checked_add(right), || {
+                    &BigInt::from(left) + &BigInt::from(right)
+                }))
+            }
+            (Some(Operands::BigIntegers(left, right)), ..) => {
+                Ok(Evaluation::integer(&left + &right))
+            }
+            (Some(Operands::Floats(left, right)), ..) => Ok(Evaluation::Number(left + right)),
+            (_, Evaluation::String(left), Evaluation::String(right)) => {
+                Ok(Evaluation::String(format!("{left}{right}")))
+            }
+            (_, left, right) => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Unsupported binary PLUS for evaluation: {:?}", (left, right)},
+                ))
+            }
+        },
+        TokenType::MINUS => match Operands::of(&left, &right) {
+            Some(Operands::Integers(left, right)) => {
+                Ok(Evaluation::integer_or(left.checked_sub(right), || {
+                    &BigInt::from(left) - &BigInt::from(right)
+                }))
+            }
+            Some(Operands::BigIntegers(left, right)) => Ok(Evaluation::integer(&left - &right)),
+            Some(Operands::Floats(left, right)) => Ok(Evaluation::Number(left - right)),
+            None => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Unsupported binary MINUS for evaluation: {:?}", (left, right)},
+                ))
+            }
+        },
+        TokenType::STAR => match Operands::of(&left, &right) {
+            Some(Operands::Integers(left, right)) => {
+                Ok(Evaluation::integer_or(left.checked_mul(right), || {
+                    &BigInt::from(left) * &BigInt::from(right)
+                }))
+            }
+            Some(Operands::BigIntegers(left, right)) => Ok(Evaluation::integer(&left * &right)),
+            Some(Operands::Floats(left, right)) => Ok(Evaluation::Number(left * right)),
+            None => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Unsupported binary STAR for evaluation: {:?}", (left, right)},
+                ))
+            }
+        },
+        TokenType::SLASH => match Operands::of(&left, &right) {
+            Some(Operands::Integers(left, right)) => {
+                Ok(Evaluation::Number(left as f64 / right as f64))
+            }
+            Some(Operands::BigIntegers(left, right)) => {
+                Ok(Evaluation::Number(left.to_f64() / right.to_f64()))
+            }
+            Some(Operands::Floats(left, right)) => Ok(Evaluation::Number(left / right)),
+            None => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Unsupported binary SLASH for evaluation: {:?}", (left, right)},
+                ))
+            }
+        },
+        TokenType::TILDE_SLASH => match Operands::of(&left, &right) {
+            Some(Operands::Integers(_, 0)) => {
+                return Err(Interruption::Error(anyhow::anyhow! {"Division by zero."}))
+            }
+            // Only the smallest integer by -1 overflows, into its negation
+            Some(Operands::Integers(left, right)) => {
+                Ok(Evaluation::integer_or(left.checked_div(right), || {
+                    -&BigInt::from(left)
+                }))
+            }
+            Some(Operands::BigIntegers(left, right)) => match left.div_rem(&right) {
+                Some((quotient, _)) => Ok(Evaluation::integer(quotient)),
+                None => return Err(Interruption::Error(anyhow::anyhow! {"Division by zero."})),
             },
-            TokenType::BANG_EQUAL => match (left, right) {
-                (Evaluation::Number(left), Evaluation::Number(right)) => {
-                    Ok(Evaluation::Boolean(left != right))
-                }
-                (Evaluation::String(left), Evaluation::String(right)) => {
-                    Ok(Evaluation::Boolean(left != right))
-                }
-                (Evaluation::Boolean(left), Evaluation::Boolean(right)) => {
-                    Ok(Evaluation::Boolean(left != right))
-                }
-                (Evaluation::Nil, Evaluation::Nil) => Ok(Evaluation::Boolean(false)),
-                _ => Ok(Evaluation::Boolean(true)),
+            Some(Operands::Floats(left, right)) => Ok(Evaluation::Number((left / right).trunc())),
+            None => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Unsupported binary TILDE_SLASH for evaluation: {:?}", (left, right)},
+                ))
+            }
+        },
+        TokenType::PERCENT => match Operands::of(&left, &right) {
+            Some(Operands::Integers(_, 0)) => {
+                return Err(Interruption::Error(anyhow::anyhow! {"Division by zero."}))
+            }
+            // The only overflow, the smallest integer by -1, has no remainder
+            Some(Operands::Integers(left, right)) => {
+                Ok(Evaluation::Integer(left.checked_rem(right).unwrap_or(0)))
+            }
+            Some(Operands::BigIntegers(left, right)) => match left.div_rem(&right) {
+                Some((_, remainder)) => Ok(Evaluation::integer(remainder)),
+                None => return Err(Interruption::Error(anyhow::anyhow! {"Division by zero."})),
             },
-            _ => {
+            Some(Operands::Floats(left, right)) => Ok(Evaluation::Number(left % right)),
+            None => {
                 return Err(Interruption::Error(
-                    anyhow::anyhow! {"Unsupported binary operator: {o}", o = &self.operator},
+                    anyhow::anyhow! {"Unsupported binary PERCENT for evaluation: {:?}", (left, right)},
                 ))
             }
+        },
+        TokenType::STAR_STAR => match left.pow(&right).map_err(Interruption::Error)? {
+            Some(result) => Ok(result),
+            None => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Unsupported binary STAR_STAR for evaluation: {:?}", (left, right)},
+                ))
+            }
+        },
+        TokenType::AMPERSAND | TokenType::PIPE | TokenType::CARET => {
+            let (left, right) =
+                Operands::bitwise(operator.lexeme, &left, &right).map_err(Interruption::Error)?;
+
+            Ok(Evaluation::Integer(match operator.token_type {
+                TokenType::AMPERSAND => left & right,
+                TokenType::PIPE => left | right,
+                _ => left ^ right,
+            }))
+        }
+        TokenType::LESS_LESS => left.shift_left(&right).map_err(Interruption::Error),
+        TokenType::GREATER_GREATER => left.shift_right(&right).map_err(Interruption::Error),
+        TokenType::LESS => match (Operands::of(&left, &right), left, right) {
+            (Some(Operands::Integers(left, right)), ..) => Ok(Evaluation::Boolean(left < right)),
+            (Some(Operands::BigIntegers(left, right)), ..) => Ok(Evaluation::Boolean(left < right)),
+            (Some(Operands::Floats(left, right)), ..) => Ok(Evaluation::Boolean(left < right)),
+            (_, Evaluation::String(left), Evaluation::String(right)) => {
+                Ok(Evaluation::Boolean(left < right))
+            }
+            (_, left, right) => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Unsupported binary LESS for evaluation: {:?}", (left, right)},
+                ))
+            }
+        },
+        TokenType::LESS_EQUAL => match (Operands::of(&left, &right), left, right) {
+            (Some(Operands::Integers(left, right)), ..) => Ok(Evaluation::Boolean(left <= right)),
+            (Some(Operands::BigIntegers(left, right)), ..) => {
+                Ok(Evaluation::Boolean(left <= right))
+            }
+            (Some(Operands::Floats(left, right)), ..) => Ok(Evaluation::Boolean(left <= right)),
+            (_, Evaluation::String(left), Evaluation::String(right)) => {
+                Ok(Evaluation::Boolean(left <= right))
+            }
+            (_, left, right) => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Unsupported binary LESS_EQUAL for evaluation: {:?}", (left, right)},
+                ))
+            }
+        },
+        TokenType::GREATER => match (Operands::of(&left, &right), left, right) {
+            (Some(Operands::Integers(left, right)), ..) => Ok(Evaluation::Boolean(left > right)),
+            (Some(Operands::BigIntegers(left, right)), ..) => Ok(Evaluation::Boolean(left > right)),
+            (Some(Operands::Floats(left, right)), ..) => Ok(Evaluation::Boolean(left > right)),
+            (_, Evaluation::String(left), Evaluation::String(right)) => {
+                Ok(Evaluation::Boolean(left > right))
+            }
+            (_, left, right) => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Unsupported binary GREATER for evaluation: {:?}", (left, right)},
+                ))
+            }
+        },
+        TokenType::GREATER_EQUAL => match (Operands::of(&left, &right), left, right) {
+            (Some(Operands::Integers(left, right)), ..) => Ok(Evaluation::Boolean(left >= right)),
+            (Some(Operands::BigIntegers(left, right)), ..) => {
+                Ok(Evaluation::Boolean(left >= right))
+            }
+            (Some(Operands::Floats(left, right)), ..) => Ok(Evaluation::Boolean(left >= right)),
+            (_, Evaluation::String(left), Evaluation::String(right)) => {
+                Ok(Evaluation::Boolean(left >= right))
+            }
+            (_, left, right) => {
+                return Err(Interruption::Error(
+                    anyhow::anyhow! {"Unsupported binary GREATER_EQUAL for evaluation: {:?}", (left, right)},
+                ));
+            }
+        },
+        TokenType::EQUAL_EQUAL => match (Operands::of(&left, &right), left, right) {
+            (Some(Operands::Integers(left, right)), ..) => Ok(Evaluation::Boolean(left == right)),
+            (Some(Operands::BigIntegers(left, right)), ..) => {
+                Ok(Evaluation::Boolean(left == right))
+            }
+            (Some(Operands::Floats(left, right)), ..) => Ok(Evaluation::Boolean(left == right)),
+            (_, Evaluation::String(left), Evaluation::String(right)) => {
+                Ok(Evaluation::Boolean(left == right))
+            }
+            (_, Evaluation::Boolean(left), Evaluation::Boolean(right)) => {
+                Ok(Evaluation::Boolean(left == right))
+            }
+            (_, Evaluation::Nil, Evaluation::Nil) => Ok(Evaluation::Boolean(true)),
+            _ => Ok(Evaluation::Boolean(false)),
+        },
+        TokenType::BANG_EQUAL => match (Operands::of(&left, &right), left, right) {
+            (Some(Operands::Integers(left, right)), ..) => Ok(Evaluation::Boolean(left != right)),
+            (Some(Operands::BigIntegers(left, right)), ..) => {
+                Ok(Evaluation::Boolean(left != right))
+            }
+            (Some(Operands::Floats(left, right)), ..) => Ok(Evaluation::Boolean(left != right)),
+            (_, Evaluation::String(left), Evaluation::String(right)) => {
+                Ok(Evaluation::Boolean(left != right))
+            }
+            (_, Evaluation::Boolean(left), Evaluation::Boolean(right)) => {
+                Ok(Evaluation::Boolean(left != right))
+            }
+            (_, Evaluation::Nil, Evaluation::Nil) => Ok(Evaluation::Boolean(false)),
+            _ => Ok(Evaluation::Boolean(true)),
+        },
+        _ => {
+            return Err(Interruption::Error(
+                anyhow::anyhow! {"Unsupported binary operator: {o}", o = &operator},
+            ))
         }
     }
 }
@@ -437,34 +608,97 @@ impl<'de> Interpret<'de> for LogicalExp<'de> {
         }
     }
 }
+impl<'de> Interpret<'de> for ConditionalExp<'de> {
+    fn interpret(
+        &self,
+        environment: Rc<RefCell<Environment<'de>>>,
+    ) -> Result<Evaluation<'de>, Interruption<'de>> {
+        let condition = self.condition.interpret(environment.clone())?;
+
+        return match condition {
+            Evaluation::Boolean(false) | Evaluation::Nil => self.else_branch.interpret(environment),
+            _ => self.then_branch.interpret(environment),
+        };
+    }
+}
+impl<'de> Interpret<'de> for CoalesceExp<'de> {
+    fn interpret(
+        &self,
+        environment: Rc<RefCell<Environment<'de>>>,
+    ) -> Result<Evaluation<'de>, Interruption<'de>> {
+        let left = self.left.interpret(environment.clone())?;
+
+        // Unlike `or`, `false` is kept
+        return match left {
+            Evaluation::Nil => self.right.interpret(environment),
+            _ => Ok(left),
+        };
+    }
+}
 impl<'de> Interpret<'de> for AssignmentExp<'de> {
     fn interpret(
         &self,
         environment: Rc<RefCell<Environment<'de>>>,
     ) -> Result<Evaluation<'de>, Interruption<'de>> {
-        let value = self.value.interpret(environment.clone())?;
+        let value = match &self.operator {
+            Some(operator) => {
+                let name = &self.name.lexeme;
+                let current = environment.borrow().get(name).ok_or_else(|| {
+                    Interruption::Error(
+                        anyhow::anyhow! {"Not initialized IDENTIFIER: {name}", name = &self.name},
+                    )
+                })?;
+                let value = self.value.interpret(environment.clone())?;
+
+                binary_operation(operator, current, value)?
+            }
+            None => self.value.interpret(environment.clone())?,
+        };
 
         let mut environment_ = environment.borrow_mut();
-        environment_.set(self.name.lexeme, value.clone());
+        environment_
+            .set(self.name.lexeme, value.clone())
+            .map_err(Interruption::Error)?;
 
         return Ok(value);
     }
 }
-impl<'de> Interpret<'de> for CallExp<'de> {
-    fn interpret(
+/// Evaluates a link of a call, property and index chain, `None` once a `?.` link found a nil
+/// receiver, the rest of the chain is skipped then.
+fn chain_link<'de>(
+    node: &Node<'de>,
+    environment: Rc<RefCell<Environment<'de>>>,
+) -> Result<Option<Evaluation<'de>>, Interruption<'de>> {
+    return match node {
+        Node::CallExp(call_exp) => call_exp.chain(environment),
+        Node::GetExp(get_exp) => get_exp.chain(environment),
+        Node::IndexExp(index_exp) => index_exp.chain(environment),
+        node => node.interpret(environment).map(Some),
+    };
+}
+impl<'de> CallExp<'de> {
+    fn chain(
         &self,
         environment: Rc<RefCell<Environment<'de>>>,
-    ) -> Result<Evaluation<'de>, Interruption<'de>> {
-        let callee = self.callee.interpret(environment.clone())?;
+    ) -> Result<Option<Evaluation<'de>>, Interruption<'de>> {
+        let Some(callee) = chain_link(&self.callee, environment.clone())? else {
+            return Ok(None);
+        };
 
         let mut args = vec![];
         for arg in self.args.iter() {
             args.push(arg.interpret(environment.clone())?);
         }
+        let mut named_args = vec![];
+        for named_arg in self.named_args.iter() {
+            let value = named_arg.value.interpret(environment.clone())?;
+            named_args.push((named_arg.name.clone(), value));
+        }
 
         fn call_class_method<'de>(
             class_method: ClassMethod<'de>,
             args: Vec<Evaluation<'de>>,
+            named_args: Vec<(Token<'de>, Evaluation<'de>)>,
         ) -> Result<Evaluation<'de>, Interruption<'de>> {
             let is_constructor = class_method.is_constructor();
 
@@ -483,7 +717,7 @@ impl<'de> Interpret<'de> for CallExp<'de> {
 
             let result = match class_method.func.as_ref() {
                 Function::CustomFunction(custom_function) => {
-                    custom_function.call_with_environment(method_environment, args)
+                    custom_function.call_with_environment(method_environment, args, named_args)
                 }
                 _ => unreachable!(),
             };
@@ -502,19 +736,28 @@ impl<'de> Interpret<'de> for CallExp<'de> {
             }
         }
 
-        match callee {
-            Evaluation::Fn(callable) => {
-                if callable.arity() != args.len() {
-                    return Err(Interruption::Error(
-                        anyhow::anyhow! {"Error. Expected {} arguments but have {}", callable.arity(),args.len() },
-                    ));
-                }
-                match callable.as_ref() {
-                    Function::CustomFunction(function) => function.call(args),
-                    Function::NativeFunction(function) => function.call(args),
+        let result = match callee {
+            Evaluation::Fn(callable) => match callable.as_ref() {
+                Function::CustomFunction(function) => function.call_with_environment(
+                    function.captured_environment.clone(),
+                    args,
+                    named_args,
+                ),
+                Function::NativeFunction(function) => {
+                    if !named_args.is_empty() {
+                        return Err(Interruption::Error(
+                            anyhow::anyhow! {"{}() doesn't take named arguments.", function.name},
+                        ));
+                    }
+                    check_arity(callable.min_arity(), callable.max_arity(), args.len())
+                        .map_err(Interruption::Error)?;
+
+                    function.call(args)
                 }
+            },
+            Evaluation::ClassMethod(class_method) => {
+                call_class_method(class_method, args, named_args)
             }
-            Evaluation::ClassMethod(class_method) => call_class_method(class_method, args),
             Evaluation::Class(class) => {
                 let class_instance = ClassInstance::new_rc(class);
 
@@ -525,7 +768,11 @@ impl<'de> Interpret<'de> for CallExp<'de> {
                     };
 
                     if let Ok(Evaluation::ClassMethod(init)) = init {
-                        call_class_method(init, args)?;
+                        call_class_method(init, args, named_args)?;
+                    } else {
+                        // Without an initializer a class takes no arguments
+                        check_arity(0, Some(0), args.len() + named_args.len())
+                            .map_err(Interruption::Error)?;
                     }
                 }
 
@@ -536,24 +783,38 @@ impl<'de> Interpret<'de> for CallExp<'de> {
                     anyhow::anyhow! {"{:?} is not callable", callee},
                 ))
             }
-        }
+        };
+
+        return result.map(Some);
     }
 }
-impl<'de> Interpret<'de> for GetExp<'de> {
+impl<'de> Interpret<'de> for CallExp<'de> {
     fn interpret(
         &self,
         environment: Rc<RefCell<Environment<'de>>>,
     ) -> Result<Evaluation<'de>, Interruption<'de>> {
-        let callee = self.callee.interpret(environment.clone())?;
+        return Ok(self.chain(environment)?.unwrap_or(Evaluation::Nil));
+    }
+}
+impl<'de> GetExp<'de> {
+    fn chain(
+        &self,
+        environment: Rc<RefCell<Environment<'de>>>,
+    ) -> Result<Option<Evaluation<'de>>, Interruption<'de>> {
+        let Some(callee) = chain_link(&self.callee, environment)? else {
+            return Ok(None);
+        };
         let name = &self.name;
 
         return match callee {
+            Evaluation::Nil if self.optional => Ok(None),
             Evaluation::ClassInstance(class_instance) => {
                 let class_instance_ = class_instance.borrow();
 
                 class_instance_
                     .get_field(name.lexeme)
-                    .map_err(|error| Interruption::Error(error))
+                    .map(Some)
+                    .map_err(Interruption::Error)
             }
             x => Err(Interruption::Error(
                 anyhow::anyhow! {"Only instances have properties. {x:?}"},
@@ -561,6 +822,57 @@ impl<'de> Interpret<'de> for GetExp<'de> {
         };
     }
 }
+impl<'de> Interpret<'de> for GetExp<'de> {
+    fn interpret(
+        &self,
+        environment: Rc<RefCell<Environment<'de>>>,
+    ) -> Result<Evaluation<'de>, Interruption<'de>> {
+        return Ok(self.chain(environment)?.unwrap_or(Evaluation::Nil));
+    }
+}
+impl<'de> IndexExp<'de> {
+    fn chain(
+        &self,
+        environment: Rc<RefCell<Environment<'de>>>,
+    ) -> Result<Option<Evaluation<'de>>, Interruption<'de>> {
+        let Some(object) = chain_link(&self.object, environment.clone())? else {
+            return Ok(None);
+        };
+        // Like the arguments of a skipped call, the index is not evaluated
+        if self.optional && matches!(object, Evaluation::Nil) {
+            return Ok(None);
+        }
+        let index = self.index.interpret(environment)?;
+
+        return object
+            .get_index(&index)
+            .map(Some)
+            .map_err(Interruption::Error);
+    }
+}
+impl<'de> Interpret<'de> for IndexExp<'de> {
+    fn interpret(
+        &self,
+        environment: Rc<RefCell<Environment<'de>>>,
+    ) -> Result<Evaluation<'de>, Interruption<'de>> {
+        return Ok(self.chain(environment)?.unwrap_or(Evaluation::Nil));
+    }
+}
+impl<'de> Interpret<'de> for InterpolationExp<'de> {
+    fn interpret(
+        &self,
+        environment: Rc<RefCell<Environment<'de>>>,
+    ) -> Result<Evaluation<'de>, Interruption<'de>> {
+        let mut result = String::new();
+        for part in self.parts.iter() {
+            // Stringified like `print` does
+            let value = part.interpret(environment.clone())?;
+            result.push_str(&value.to_string());
+        }
+
+        return Ok(Evaluation::String(result));
+    }
+}
 impl<'de> Interpret<'de> for SetExp<'de> {
     fn interpret(
         &self,
@@ -572,12 +884,27 @@ impl<'de> Interpret<'de> for SetExp<'de> {
         };
         let calle = get_exp.callee.interpret(environment.clone())?;
         let name = &get_exp.name;
-        let value = self.value.interpret(environment)?;
+
+        // The object is evaluated once, a compound assignment reads the field from it
+        let value = match (&self.operator, &calle) {
+            (Some(operator), Evaluation::ClassInstance(class_instance)) => {
+                let current = class_instance
+                    .borrow()
+                    .get_field(name.lexeme)
+                    .map_err(Interruption::Error)?;
+                let value = self.value.interpret(environment)?;
+
+                binary_operation(operator, current, value)?
+            }
+            _ => self.value.interpret(environment)?,
+        };
 
         return match calle {
             Evaluation::ClassInstance(class_instance) => {
                 let mut class_instance_ = class_instance.borrow_mut();
-                class_instance_.set_field(name.lexeme, value.clone());
+                class_instance_
+                    .set_field(name.lexeme, value.clone())
+                    .map_err(Interruption::Error)?;
 
                 Ok(value)
             }
@@ -641,7 +968,7 @@ impl<'de> Interpret<'de> for SuperExp<'de> {
 
             this_class_instance_
                 .get_filed_for_class(super_class, self.method.lexeme)
-                .map_err(|error| Interruption::Error(error))
+                .map_err(Interruption::Error)
         }
     }
 }
@@ -6,6 +6,7 @@ use super::Evaluation;
 pub enum Interruption<'de> {
     Error(anyhow::Error),
     Return(Evaluation<'de>),
+    Exit(i32),
 }
 
 impl Display for Interruption<'_> {
@@ -13,6 +14,7 @@ impl Display for Interruption<'_> {
         match self {
             Interruption::Error(err) => write!(f, "Error: {}", err),
             Interruption::Return(_) => write!(f, "Return"),
+            Interruption::Exit(code) => write!(f, "Exit {code}"),
         }
     }
 }
@@ -21,7 +23,7 @@ impl Error for Interruption<'_> {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             Interruption::Error(err) => err.source(),
-            Interruption::Return(_) => None,
+            Interruption::Return(_) | Interruption::Exit(_) => None,
         }
     }
 }
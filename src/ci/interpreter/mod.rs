@@ -1,12 +1,14 @@
 pub(crate) mod evaluation;
 pub(crate) mod interpreter;
 pub(crate) mod interruption;
+pub(crate) mod options;
 
 use std::{cell::RefCell, rc::Rc};
 
 pub use evaluation::Evaluation;
 pub use interpreter::{Interpret, Interpreter};
 pub use interruption::Interruption;
+pub use options::RuntimeOptions;
 
 use super::{Environment, Node};
 
@@ -31,9 +33,13 @@ impl<'de> Interpret<'de> for Node<'de> {
             Node::UnaryExp(unary_exp) => unary_exp.interpret(environment),
             Node::BinaryExp(binary_exp) => binary_exp.interpret(environment),
             Node::LogicalExp(logical_exp) => logical_exp.interpret(environment),
+            Node::ConditionalExp(conditional_exp) => conditional_exp.interpret(environment),
+            Node::CoalesceExp(coalesce_exp) => coalesce_exp.interpret(environment),
             Node::AssignmentExp(assignment_exp) => assignment_exp.interpret(environment),
             Node::CallExp(call_exp) => call_exp.interpret(environment),
             Node::GetExp(get_exp) => get_exp.interpret(environment),
+            Node::IndexExp(index_exp) => index_exp.interpret(environment),
+            Node::InterpolationExp(interpolation_exp) => interpolation_exp.interpret(environment),
             Node::SetExp(set_exp) => set_exp.interpret(environment),
             Node::ThisExp(this_exp) => this_exp.interpret(environment),
             Node::SuperExp(super_exp) => super_exp.interpret(environment),
@@ -0,0 +1,10 @@
+/// Settings of a single interpreter run, usually coming from the command line.
+#[derive(Debug, Clone, Default)]
+pub struct RuntimeOptions {
+    /// Seed of the random number generator, a time based one is used if not set.
+    pub seed: Option<u64>,
+    /// Disables the natives touching the filesystem, for sandboxed runs.
+    pub no_filesystem: bool,
+    /// Arguments passed to the script after `--`.
+    pub args: Vec<String>,
+}
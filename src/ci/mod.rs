@@ -8,6 +8,6 @@ mod traverser;
 pub use class::{Class, ClassInstance};
 pub use environment::Environment;
 pub use function::Function;
-pub use grammar::{Debuge, Node, Parser};
-pub use interpreter::{Evaluation, Interpret, Interpreter};
+pub use grammar::{Debuge, DotGraph, Dotify, Jsonify, Node, Parser};
+pub use interpreter::{Evaluation, Interpret, Interpreter, RuntimeOptions};
 pub use traverser::Traverser;
@@ -24,9 +24,13 @@ impl<'de> Traverse<'de> for Node<'de> {
             Node::UnaryExp(unary_exp) => unary_exp.traverse(traverser),
             Node::BinaryExp(binary_exp) => binary_exp.traverse(traverser),
             Node::LogicalExp(logical_exp) => logical_exp.traverse(traverser),
+            Node::ConditionalExp(conditional_exp) => conditional_exp.traverse(traverser),
+            Node::CoalesceExp(coalesce_exp) => coalesce_exp.traverse(traverser),
             Node::AssignmentExp(assignment_exp) => assignment_exp.traverse(traverser),
             Node::CallExp(call_exp) => call_exp.traverse(traverser),
             Node::GetExp(get_exp) => get_exp.traverse(traverser),
+            Node::IndexExp(index_exp) => index_exp.traverse(traverser),
+            Node::InterpolationExp(interpolation_exp) => interpolation_exp.traverse(traverser),
             Node::SetExp(set_exp) => set_exp.traverse(traverser),
             Node::ThisExp(this_exp) => this_exp.traverse(traverser),
             Node::SuperExp(super_exp) => super_exp.traverse(traverser),
@@ -1,4 +1,4 @@
-use std::{cell::RefCell, collections::HashSet, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
 
 use crate::ci::class::INIT_STR;
 use crate::ci::Node;
@@ -16,16 +16,26 @@ struct Class<'de> {
 
 pub struct Traverser<'de> {
     var_initialization: Option<&'de str>,
-    scopes_stack: Vec<HashSet<&'de str>>,
+    /// Names declared in each scope, mapped to whether they are constants
+    scopes_stack: Vec<HashMap<&'de str, bool>>,
+    /// Line and name of the first assignment to a constant
+    const_assignment: Option<(usize, &'de str)>,
     funcs_stack: Vec<&'de str>,
     classes_stack: Vec<Class<'de>>,
 }
 
+impl<'de> Default for Traverser<'de> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<'de> Traverser<'de> {
     pub fn new() -> Self {
         Self {
             var_initialization: None,
-            scopes_stack: vec![HashSet::new() /* Global scope */],
+            scopes_stack: vec![HashMap::new() /* Global scope */],
+            const_assignment: None,
             funcs_stack: vec![],
             classes_stack: vec![],
         }
@@ -69,6 +79,13 @@ impl<'de> Traverser<'de> {
         if result.is_use_super_in_class_with_no_superclass() {
             anyhow::bail! {"Can't use 'super' in a class with no superclass."}
         }
+        if result.is_const_assignment() {
+            let (line, name) = traverser
+                .borrow()
+                .const_assignment
+                .expect("Set along with the result");
+            anyhow::bail! {"[line {line}] Error at '{name}': Can't assign to constant '{name}'."}
+        }
 
         return anyhow::Ok(());
     }
@@ -88,14 +105,19 @@ impl<'de> Traverse<'de> for VarDecl<'de> {
         {
             let mut traverser_ = traverser.borrow_mut();
 
-            let scope_already_contains = traverser_
+            let declared = traverser_
                 .scopes_stack
                 .last()
                 .expect("Global scope always exists")
-                .contains(self.name.lexeme);
+                .get(self.name.lexeme)
+                .copied();
 
-            if scope_already_contains && !traverser_.is_global_scope() {
-                return TraverseResult::scope_var_redeclaration();
+            // Globals can be redeclared, unless that would clobber a constant
+            match declared {
+                Some(constant) if constant || !traverser_.is_global_scope() => {
+                    return TraverseResult::scope_var_redeclaration();
+                }
+                _ => {}
             }
 
             assert!(
@@ -107,7 +129,7 @@ impl<'de> Traverse<'de> for VarDecl<'de> {
                 .scopes_stack
                 .last_mut()
                 .expect("Global scope always exists")
-                .insert(self.name.lexeme);
+                .insert(self.name.lexeme, self.constant);
         }
 
         let mut result = TraverseResult::ok();
@@ -123,29 +145,49 @@ impl<'de> Traverse<'de> for VarDecl<'de> {
         return result;
     }
 }
+/// Declares the parameters in the function scope, each default is traversed before its own
+/// parameter exists, like a variable initializer.
+fn declare_parameters<'de>(
+    traverser: Rc<RefCell<Traverser<'de>>>,
+    parameters: &[Parameter<'de>],
+) -> TraverseResult {
+    let mut result = TraverseResult::ok();
+
+    for parameter in parameters.iter() {
+        if let Some(default) = &parameter.default {
+            result |= default.traverse(traverser.clone());
+        }
+
+        let mut traverser_ = traverser.borrow_mut();
+        let func_scope = traverser_
+            .scopes_stack
+            .last_mut()
+            .expect("There should always be a func scope");
+
+        if func_scope.contains_key(&parameter.name.lexeme) {
+            return TraverseResult::scope_var_redeclaration();
+        }
+
+        func_scope.insert(parameter.name.lexeme, false);
+    }
+
+    return result;
+}
 impl<'de> Traverse<'de> for FunctionDecl<'de> {
     fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
         {
             let mut traverser_ = traverser.borrow_mut();
-            traverser_.scopes_stack.push(HashSet::new());
-
-            let func_scope = traverser_
-                .scopes_stack
-                .last_mut()
-                .expect("There should always be a func scope");
-
-            for parameter in self.parameters.iter() {
-                if func_scope.contains(&parameter.lexeme) {
-                    return TraverseResult::scope_var_redeclaration();
-                }
+            traverser_.scopes_stack.push(HashMap::new());
+        }
 
-                func_scope.insert(parameter.lexeme);
-            }
+        let mut result = declare_parameters(traverser.clone(), &self.parameters);
 
+        {
+            let mut traverser_ = traverser.borrow_mut();
             traverser_.funcs_stack.push(self.name.lexeme);
         }
 
-        let result = self.body.traverse(traverser.clone());
+        result |= self.body.traverse(traverser.clone());
 
         {
             let mut traverser_ = traverser.borrow_mut();
@@ -162,25 +204,17 @@ impl<'de> Traverse<'de> for ClassMethodDecl<'de> {
     fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
         {
             let mut traverser_ = traverser.borrow_mut();
-            traverser_.scopes_stack.push(HashSet::new());
-
-            let func_scope = traverser_
-                .scopes_stack
-                .last_mut()
-                .expect("There should always be a func scope");
-
-            for parameter in self.parameters.iter() {
-                if func_scope.contains(&parameter.lexeme) {
-                    return TraverseResult::scope_var_redeclaration();
-                }
+            traverser_.scopes_stack.push(HashMap::new());
+        }
 
-                func_scope.insert(parameter.lexeme);
-            }
+        let mut result = declare_parameters(traverser.clone(), &self.parameters);
 
+        {
+            let mut traverser_ = traverser.borrow_mut();
             traverser_.funcs_stack.push(self.name.lexeme);
         }
 
-        let result = self.body.traverse(traverser.clone());
+        result |= self.body.traverse(traverser.clone());
 
         {
             let mut traverser_ = traverser.borrow_mut();
@@ -244,20 +278,17 @@ impl<'de> Traverse<'de> for LiteralExp<'de> {
         let mut result = TraverseResult::ok();
 
         let traverser_ = traverser.borrow();
-        match self.name.token_type {
-            TokenType::IDENTIFIER => {
-                let name = self.name.lexeme;
-
-                if traverser_
-                    .var_initialization
-                    .map(|var_name| var_name == name)
-                    .unwrap_or(false)
-                    && !traverser_.is_global_scope()
-                {
-                    result |= TraverseResult::own_var_initialization();
-                }
+        if let TokenType::IDENTIFIER = self.name.token_type {
+            let name = self.name.lexeme;
+
+            if traverser_
+                .var_initialization
+                .map(|var_name| var_name == name)
+                .unwrap_or(false)
+                && !traverser_.is_global_scope()
+            {
+                result |= TraverseResult::own_var_initialization();
             }
-            _ => {}
         }
 
         return result;
@@ -269,23 +300,72 @@ impl<'de> Traverse<'de> for GroupingExp<'de> {
     }
 }
 impl<'de> Traverse<'de> for UnaryExp<'de> {
-    fn traverse(&self, #[allow(unused)] traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
-        return TraverseResult::ok();
+    fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
+        return self.right.traverse(traverser);
     }
 }
 impl<'de> Traverse<'de> for BinaryExp<'de> {
-    fn traverse(&self, #[allow(unused)] traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
-        return TraverseResult::ok();
+    fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
+        let mut result = TraverseResult::ok();
+
+        result |= self.left.traverse(traverser.clone());
+        result |= self.right.traverse(traverser);
+
+        return result;
     }
 }
 impl<'de> Traverse<'de> for LogicalExp<'de> {
-    fn traverse(&self, #[allow(unused)] traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
-        return TraverseResult::ok();
+    fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
+        let mut result = TraverseResult::ok();
+
+        result |= self.left.traverse(traverser.clone());
+        result |= self.right.traverse(traverser);
+
+        return result;
+    }
+}
+impl<'de> Traverse<'de> for ConditionalExp<'de> {
+    fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
+        let mut result = TraverseResult::ok();
+
+        result |= self.condition.traverse(traverser.clone());
+        result |= self.then_branch.traverse(traverser.clone());
+        result |= self.else_branch.traverse(traverser);
+
+        return result;
+    }
+}
+impl<'de> Traverse<'de> for CoalesceExp<'de> {
+    fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
+        let mut result = TraverseResult::ok();
+
+        result |= self.left.traverse(traverser.clone());
+        result |= self.right.traverse(traverser);
+
+        return result;
     }
 }
 impl<'de> Traverse<'de> for AssignmentExp<'de> {
     fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
-        return self.value.traverse(traverser);
+        let mut result = self.value.traverse(traverser.clone());
+
+        let mut traverser_ = traverser.borrow_mut();
+        // The innermost declaration wins, a constant may be shadowed by a variable
+        let constant = traverser_
+            .scopes_stack
+            .iter()
+            .rev()
+            .find_map(|scope| scope.get(self.name.lexeme).copied())
+            .unwrap_or(false);
+
+        if constant {
+            if traverser_.const_assignment.is_none() {
+                traverser_.const_assignment = Some((self.name.line, self.name.lexeme));
+            }
+            result |= TraverseResult::const_assignment();
+        }
+
+        return result;
     }
 }
 impl<'de> Traverse<'de> for CallExp<'de> {
@@ -297,6 +377,9 @@ impl<'de> Traverse<'de> for CallExp<'de> {
         for arg in self.args.iter() {
             result |= arg.traverse(traverser.clone());
         }
+        for named_arg in self.named_args.iter() {
+            result |= named_arg.value.traverse(traverser.clone());
+        }
 
         return result;
     }
@@ -306,6 +389,27 @@ impl<'de> Traverse<'de> for GetExp<'de> {
         return self.callee.traverse(traverser);
     }
 }
+impl<'de> Traverse<'de> for IndexExp<'de> {
+    fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
+        let mut result = TraverseResult::ok();
+
+        result |= self.object.traverse(traverser.clone());
+        result |= self.index.traverse(traverser);
+
+        return result;
+    }
+}
+impl<'de> Traverse<'de> for InterpolationExp<'de> {
+    fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
+        let mut result = TraverseResult::ok();
+
+        for part in self.parts.iter() {
+            result |= part.traverse(traverser.clone());
+        }
+
+        return result;
+    }
+}
 impl<'de> Traverse<'de> for SetExp<'de> {
     fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
         let mut result = TraverseResult::ok();
@@ -357,7 +461,7 @@ impl<'de> Traverse<'de> for BlockStm<'de> {
     fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
         {
             let mut traverser_ = traverser.borrow_mut();
-            traverser_.scopes_stack.push(HashSet::new());
+            traverser_.scopes_stack.push(HashMap::new());
         }
 
         let mut result = TraverseResult::ok();
@@ -389,6 +493,7 @@ impl<'de> Traverse<'de> for IfElseStm<'de> {
     fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
         let mut result = TraverseResult::ok();
 
+        result |= self.condition.traverse(traverser.clone());
         result |= self.then_branch.traverse(traverser.clone());
         if let Some(else_branch) = &self.else_branch {
             result |= else_branch.traverse(traverser);
@@ -399,7 +504,12 @@ impl<'de> Traverse<'de> for IfElseStm<'de> {
 }
 impl<'de> Traverse<'de> for WhileStm<'de> {
     fn traverse(&self, traverser: Rc<RefCell<Traverser<'de>>>) -> TraverseResult {
-        return self.body.traverse(traverser);
+        let mut result = TraverseResult::ok();
+
+        result |= self.condition.traverse(traverser.clone());
+        result |= self.body.traverse(traverser);
+
+        return result;
     }
 }
 impl<'de> Traverse<'de> for ExpressionStm<'de> {
@@ -435,7 +545,7 @@ impl<'de> Traverse<'de> for ReturnStm<'de> {
 }
 
 pub struct TraverseResult {
-    state: u8,
+    state: u16,
 }
 
 impl TraverseResult {
@@ -493,6 +603,12 @@ impl TraverseResult {
         }
     }
 
+    fn const_assignment() -> TraverseResult {
+        Self {
+            state: TraverseResult::CONST_ASSIGNMENT,
+        }
+    }
+
     fn is_own_var_initialization(&self) -> bool {
         self.state & TraverseResult::OWN_VAR_INITIALIZATION > 0
     }
@@ -525,15 +641,20 @@ impl TraverseResult {
         self.state & TraverseResult::USE_SUPER_IN_CLASS_WITH_NO_SUPERCLASS > 0
     }
 
-    const OK: u8 = 0;
-    const OWN_VAR_INITIALIZATION: u8 = 1 << 0;
-    const SCOPE_VAR_REDECLARATION: u8 = 1 << 1;
-    const INVALID_RETURN: u8 = 1 << 2;
-    const THIS_OUTSIDE_OF_CLASS: u8 = 1 << 3;
-    const RETURN_VALUE_FROM_INITIALIZER: u8 = 1 << 4;
-    const ITSELF_INHERITENCE: u8 = 1 << 5;
-    const USE_SUPER_OUTSIDE_OF_CLASS: u8 = 1 << 6;
-    const USE_SUPER_IN_CLASS_WITH_NO_SUPERCLASS: u8 = 1 << 7;
+    fn is_const_assignment(&self) -> bool {
+        self.state & TraverseResult::CONST_ASSIGNMENT > 0
+    }
+
+    const OK: u16 = 0;
+    const OWN_VAR_INITIALIZATION: u16 = 1 << 0;
+    const SCOPE_VAR_REDECLARATION: u16 = 1 << 1;
+    const INVALID_RETURN: u16 = 1 << 2;
+    const THIS_OUTSIDE_OF_CLASS: u16 = 1 << 3;
+    const RETURN_VALUE_FROM_INITIALIZER: u16 = 1 << 4;
+    const ITSELF_INHERITENCE: u16 = 1 << 5;
+    const USE_SUPER_OUTSIDE_OF_CLASS: u16 = 1 << 6;
+    const USE_SUPER_IN_CLASS_WITH_NO_SUPERCLASS: u16 = 1 << 7;
+    const CONST_ASSIGNMENT: u16 = 1 << 8;
 }
 
 impl std::ops::BitOrAssign for TraverseResult {
@@ -0,0 +1,248 @@
+use crate::{ci::Parser, Scanner, Token, TokenType};
+
+const INDENT: &str = "    ";
+
+/// Reformats a program to the canonical style: 4 spaces indentation, braces on the line
+/// of their statement, spaces around binary operators, one statement per line and at most
+/// one blank line in a row. Comments are kept in place.
+pub fn format(source: &str) -> anyhow::Result<String> {
+    // Only valid programs are formatted, the layout relies on the grammar
+    let _ = Parser::new(source, Scanner::new(source)).parse_program()?;
+
+    let tokens = Scanner::with_trivia(source)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| anyhow::anyhow! { e.to_string() })?;
+
+    let mut formatter = Formatter::new();
+    if source.starts_with("#!") {
+        // The scanner skips the shebang line, it's kept as is
+        formatter
+            .output
+            .push_str(source.lines().next().unwrap_or_default());
+        formatter.pending_newline = true;
+    }
+
+    return Ok(formatter.format(&tokens));
+}
+
+struct Formatter<'a, 'de> {
+    output: String,
+    indent: usize,
+    // Depth of `(` and `[`, semicolons inside a `for` header don't end the line
+    paren_depth: usize,
+    // The line ends once the next token or comment is known
+    pending_newline: bool,
+    // Line breaks in the source since the last written token or comment
+    newlines: usize,
+    previous: Option<&'a Token<'de>>,
+    // `name:` right after `(` or `,` is a named argument, any other `:` is a conditional's
+    before_previous: Option<&'a Token<'de>>,
+    previous_unary: bool,
+    // A block comment was written inside the line, the next token is separated from it
+    inline_comment: bool,
+}
+
+impl<'a, 'de> Formatter<'a, 'de> {
+    fn new() -> Self {
+        Self {
+            output: String::new(),
+            indent: 0,
+            paren_depth: 0,
+            pending_newline: false,
+            newlines: 0,
+            previous: None,
+            before_previous: None,
+            previous_unary: false,
+            inline_comment: false,
+        }
+    }
+
+    fn format(mut self, tokens: &'a [Token<'de>]) -> String {
+        for (i, token) in tokens.iter().enumerate() {
+            match token.token_type {
+                TokenType::NEWLINE => self.newlines += 1,
+                TokenType::COMMENT => self.comment(token),
+                TokenType::EOF => break,
+                _ => {
+                    let next = tokens[i + 1..].iter().find(|token| {
+                        !matches!(token.token_type, TokenType::NEWLINE | TokenType::COMMENT)
+                    });
+                    self.token(token, next);
+                }
+            }
+        }
+
+        if !self.output.is_empty() {
+            self.output.push('\n');
+        }
+
+        self.output
+    }
+
+    fn comment(&mut self, comment: &'a Token<'de>) {
+        let text = comment.lexeme.trim_end();
+
+        if self.previous.is_some() && self.newlines == 0 {
+            // Trailing comment, stays on the line of the code before it
+            if !self.output.ends_with(['(', '[']) {
+                self.output.push(' ');
+            }
+            self.output.push_str(text);
+
+            // Unlike line comments, block comments don't end the line
+            if text.starts_with("/*") {
+                self.inline_comment = true;
+                return;
+            }
+        } else {
+            if !self.output.is_empty() {
+                self.pending_newline = true;
+            }
+            self.start_line(false);
+            self.output.push_str(text);
+        }
+
+        self.pending_newline = true;
+        self.newlines = 0;
+    }
+
+    fn token(&mut self, token: &'a Token<'de>, next: Option<&Token<'de>>) {
+        match token.token_type {
+            TokenType::RIGHT_BRACE => {
+                self.indent = self.indent.saturating_sub(1);
+
+                // Nothing, not even a comment, was written since the opening brace
+                if self.output.ends_with('{') {
+                    self.pending_newline = false;
+                } else {
+                    self.start_line(true);
+                }
+            }
+            _ if self.pending_newline || self.previous.is_none() => self.start_line(false),
+            _ => {
+                if self.space_before(token) {
+                    self.output.push(' ');
+                }
+            }
+        }
+
+        self.output.push_str(token.lexeme);
+        self.inline_comment = false;
+        self.previous_unary = self.is_unary(token);
+        self.before_previous = self.previous;
+        self.previous = Some(token);
+        self.newlines = 0;
+
+        match token.token_type {
+            TokenType::LEFT_PAREN | TokenType::LEFT_BRACKET => self.paren_depth += 1,
+            TokenType::RIGHT_PAREN | TokenType::RIGHT_BRACKET => {
+                self.paren_depth = self.paren_depth.saturating_sub(1)
+            }
+            TokenType::LEFT_BRACE => {
+                self.indent += 1;
+                self.pending_newline = true;
+            }
+            TokenType::RIGHT_BRACE => {
+                self.pending_newline = !matches!(
+                    next.map(|next| &next.token_type),
+                    Some(
+                        TokenType::ELSE
+                            | TokenType::SEMICOLON
+                            | TokenType::RIGHT_PAREN
+                            | TokenType::COMMA
+                    )
+                );
+            }
+            TokenType::SEMICOLON => self.pending_newline = self.paren_depth == 0,
+            _ => {}
+        }
+    }
+
+    /// Ends the pending line, keeping one blank line where the source had some.
+    fn start_line(&mut self, closing_brace: bool) {
+        if self.pending_newline {
+            self.output.push('\n');
+
+            let after_opening_brace = self.output.ends_with("{\n");
+            if self.newlines >= 2 && !closing_brace && !after_opening_brace {
+                self.output.push('\n');
+            }
+        }
+        self.pending_newline = false;
+
+        self.output.push_str(&INDENT.repeat(self.indent));
+    }
+
+    fn space_before(&self, token: &Token<'de>) -> bool {
+        let Some(previous) = self.previous else {
+            return false;
+        };
+
+        if self.inline_comment {
+            return true;
+        }
+
+        if self.previous_unary {
+            return false;
+        }
+
+        if matches!(
+            previous.token_type,
+            TokenType::LEFT_PAREN
+                | TokenType::LEFT_BRACKET
+                | TokenType::DOT
+                | TokenType::QUESTION_DOT
+                | TokenType::DOT_DOT_DOT
+                | TokenType::INTERPOLATION(_)
+        ) {
+            return false;
+        }
+
+        return match token.token_type {
+            // The rest of an interpolated string, after its embedded expression
+            TokenType::STRING(_) | TokenType::INTERPOLATION(_) if token.lexeme.starts_with('}') => {
+                false
+            }
+            TokenType::SEMICOLON
+            | TokenType::COMMA
+            | TokenType::DOT
+            | TokenType::QUESTION_DOT
+            | TokenType::RIGHT_PAREN
+            | TokenType::RIGHT_BRACKET => false,
+            TokenType::COLON => !self.before_previous.is_some_and(|before_previous| {
+                matches!(
+                    before_previous.token_type,
+                    TokenType::LEFT_PAREN | TokenType::COMMA
+                )
+            }),
+            // Calls and indexing stick to their callee
+            TokenType::LEFT_PAREN | TokenType::LEFT_BRACKET => !Self::ends_operand(previous),
+            _ => true,
+        };
+    }
+
+    fn is_unary(&self, token: &Token<'de>) -> bool {
+        return match token.token_type {
+            TokenType::BANG | TokenType::TILDE => true,
+            TokenType::MINUS => !self.previous.is_some_and(Self::ends_operand),
+            _ => false,
+        };
+    }
+
+    fn ends_operand(token: &Token<'de>) -> bool {
+        return matches!(
+            token.token_type,
+            TokenType::IDENTIFIER
+                | TokenType::STRING(_)
+                | TokenType::NUMBER(_)
+                | TokenType::INTEGER(_)
+                | TokenType::BIG_INTEGER(_)
+                | TokenType::TRUE
+                | TokenType::FALSE
+                | TokenType::NIL
+                | TokenType::THIS
+                | TokenType::RIGHT_PAREN
+                | TokenType::RIGHT_BRACKET
+        );
+    }
+}
@@ -1,4 +1,8 @@
 pub mod scanner;
 pub use scanner::{Scanner, Token, TokenType};
 
+pub mod bigint;
+pub use bigint::BigInt;
+
 pub mod ci;
+pub mod formatter;
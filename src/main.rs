@@ -1,11 +1,9 @@
 use clap::Parser;
 use clap::Subcommand;
-use std::cell::RefCell;
 use std::fs;
-use std::io::{self, Write};
-use std::path::PathBuf;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 use std::process::exit;
-use std::rc::Rc;
 
 use lox_interpreter as lox;
 
@@ -16,57 +14,165 @@ struct Args {
     command: Command,
 }
 
+#[derive(clap::Args, Debug)]
+struct Input {
+    /// Path to the program, `-` reads it from stdin
+    #[arg(required_unless_present = "eval")]
+    filename: Option<PathBuf>,
+    /// Program source passed inline instead of a file
+    #[arg(short, long, conflicts_with = "filename")]
+    eval: Option<String>,
+}
+
+impl Input {
+    /// Reads the program source, exits with 66 (EX_NOINPUT) if it can't be read.
+    fn read(self) -> String {
+        if let Some(source) = self.eval {
+            return source;
+        }
+
+        let filename = self
+            .filename
+            .expect("Either a filename or --eval is required");
+        let result = if filename == Path::new("-") {
+            let mut source = String::new();
+            io::stdin().read_to_string(&mut source).map(|_| source)
+        } else {
+            fs::read_to_string(&filename)
+        };
+
+        result.unwrap_or_else(|error| {
+            match error.kind() {
+                io::ErrorKind::NotFound => eprintln!("File not found: {}", filename.display()),
+                _ => eprintln!("Failed to read file {}: {error}", filename.display()),
+            }
+            exit(66)
+        })
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    /// Human-readable output
+    Text,
+    /// Machine-readable JSON output
+    Json,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum AstFormat {
+    /// Human-readable output
+    Text,
+    /// Machine-readable JSON output
+    Json,
+    /// Graphviz graph, render it with `dot -Tsvg`
+    Dot,
+}
+
 #[derive(Subcommand, Debug)]
 enum Command {
-    Tokenize { filename: PathBuf },
-    Parse { filename: PathBuf },
-    Evaluate { filename: PathBuf },
-    Run { filename: PathBuf },
+    Tokenize {
+        #[command(flatten)]
+        input: Input,
+        /// Output format of the tokens
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    Parse {
+        #[command(flatten)]
+        input: Input,
+        /// Output format of the syntax tree
+        #[arg(long, value_enum, default_value_t = AstFormat::Text)]
+        format: AstFormat,
+    },
+    Evaluate {
+        #[command(flatten)]
+        input: Input,
+    },
+    /// Reformat a program to the canonical style, in place for files
+    Fmt {
+        #[command(flatten)]
+        input: Input,
+        /// Only check the formatting, exit with 1 if the program isn't formatted
+        #[arg(long)]
+        check: bool,
+    },
+    Run {
+        #[command(flatten)]
+        input: Input,
+        /// Seed of the random number generator, to replay a run exactly
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Disable filesystem access from the script
+        #[arg(long)]
+        no_fs: bool,
+        /// Arguments available to the script through `args()`
+        #[arg(last = true)]
+        args: Vec<String>,
+    },
 }
 
 fn main() -> anyhow::Result<()> {
     let args = Args::parse();
 
     match args.command {
-        Command::Tokenize { filename } => {
-            let file_content = fs::read_to_string(&filename).unwrap_or_else(|_| {
-                writeln!(io::stderr(), "Failed to read file {}", filename.display()).unwrap();
-                String::new()
-            });
+        Command::Tokenize { input, format } => {
+            use lox::ci::Jsonify;
+
+            let file_content = input.read();
 
-            let file_content = dbg!(file_content);
             let scanner = lox::Scanner::new(&file_content);
             let mut success = true;
+            let mut tokens = vec![];
             for token in scanner.into_iter() {
                 match token {
-                    Ok(token) => println!("{token}"),
+                    Ok(token) => match format {
+                        Format::Text => println!("{token}"),
+                        Format::Json => tokens.push(token.to_json()),
+                    },
                     Err(error) => {
                         success = false;
-                        eprintln!("{}", error.to_string());
+                        eprintln!("{}", error);
                     }
                 }
             }
 
+            if format == Format::Json {
+                println!("[\n  {}\n]", tokens.join(",\n  "));
+            }
+
             if !success {
                 exit(65)
             }
         }
-        Command::Parse { filename } => {
-            let file_content = fs::read_to_string(&filename).unwrap_or_else(|_| {
-                writeln!(io::stderr(), "Failed to read file {}", filename.display()).unwrap();
-                String::new()
-            });
-
-            let file_content = dbg!(file_content);
+        Command::Parse { input, format } => {
+            let file_content = input.read();
 
             {
-                use lox::ci::Debuge;
+                use lox::ci::{Debuge, DotGraph, Jsonify};
 
                 let scanner = lox::Scanner::new(&file_content);
-                let mut parser = lox::ci::Parser::new(&file_content, scanner);
+                let parser = lox::ci::Parser::new(&file_content, scanner);
 
-                match parser.parse_expression() {
-                    Ok(result) => println!("{s}", s = result.print()),
+                match parser.parse_program() {
+                    Ok(statements) => match format {
+                        AstFormat::Text => {
+                            for statement in statements.iter() {
+                                println!("{s}", s = statement.print());
+                            }
+                        }
+                        AstFormat::Json => {
+                            let statements = statements
+                                .iter()
+                                .map(|statement| statement.to_json())
+                                .collect::<Vec<_>>();
+                            println!("[{s}]", s = statements.join(","));
+                        }
+                        AstFormat::Dot => {
+                            let statements = statements.iter().map(|statement| statement.as_ref());
+                            print!("{s}", s = DotGraph::render(statements));
+                        }
+                    },
                     Err(error) => {
                         eprintln!("{error}");
                         exit(65);
@@ -74,45 +180,72 @@ fn main() -> anyhow::Result<()> {
                 };
             }
         }
-        Command::Evaluate { filename } => {
-            let file_content = fs::read_to_string(&filename).unwrap_or_else(|_| {
-                writeln!(io::stderr(), "Failed to read file {}", filename.display()).unwrap();
-                String::new()
-            });
+        Command::Evaluate { input } => {
+            let file_content = input.read();
 
             let scanner = lox::Scanner::new(&file_content);
+            let parser = lox::ci::Parser::new(&file_content, scanner);
 
-            {
-                use lox::ci::Debuge;
-                use lox::ci::Interpret;
-
-                let mut parser = lox::ci::Parser::new(&file_content, scanner);
-                match parser.parse_expression() {
-                    Ok(result) => {
-                        dbg!(format!("{result}", result = result.print()));
-
-                        match result
-                            .interpret(Rc::new(RefCell::new(lox::ci::Environment::default())))
-                        {
-                            Ok(ev) => println!("{ev}"),
-                            Err(error) => {
-                                eprintln!("{error}");
-                                exit(70);
-                            }
-                        }
-                    }
-                    Err(error) => {
-                        eprintln!("{error}");
-                        exit(65);
+            let statements = match parser.parse_program() {
+                Ok(statements) => statements,
+                Err(error) => {
+                    eprintln!("{error}");
+                    exit(65);
+                }
+            };
+
+            let traverser = lox::ci::Traverser::new();
+            if let Err(error) = traverser.run(&statements) {
+                eprintln!("{error}");
+                exit(65);
+            }
+
+            let mut interpreter = lox::ci::Interpreter::new(statements);
+            let value = interpreter.evaluate();
+            if let Some(value) = value {
+                println!("{value}");
+            }
+        }
+        Command::Fmt { input, check } => {
+            // Files are rewritten in place, programs from stdin or `--eval` are printed
+            let path = input.filename.clone().filter(|path| path != Path::new("-"));
+            let file_content = input.read();
+
+            let formatted = match lox::formatter::format(&file_content) {
+                Ok(formatted) => formatted,
+                Err(error) => {
+                    eprintln!("{error}");
+                    exit(65);
+                }
+            };
+
+            let name = path
+                .as_ref()
+                .map(|path| path.display().to_string())
+                .unwrap_or_else(|| "<stdin>".to_string());
+            if check {
+                if formatted != file_content {
+                    eprintln!("{name} is not formatted");
+                    exit(1);
+                }
+            } else if let Some(path) = path {
+                if formatted != file_content {
+                    if let Err(error) = fs::write(&path, formatted) {
+                        eprintln!("Failed to write file {name}: {error}");
+                        exit(74);
                     }
-                };
+                }
+            } else {
+                print!("{formatted}");
             }
         }
-        Command::Run { filename } => {
-            let file_content = fs::read_to_string(&filename).unwrap_or_else(|_| {
-                writeln!(io::stderr(), "Failed to read file {}", filename.display()).unwrap();
-                String::new()
-            });
+        Command::Run {
+            input,
+            seed,
+            no_fs,
+            args,
+        } => {
+            let file_content = input.read();
 
             let scanner = lox::Scanner::new(&file_content);
             let parser = lox::ci::Parser::new(&file_content, scanner);
@@ -120,15 +253,17 @@ fn main() -> anyhow::Result<()> {
             let statements = parser.parse_statements().into_iter().collect::<Vec<_>>();
 
             let traverser = lox::ci::Traverser::new();
-            match traverser.run(&statements) {
-                Err(error) => {
-                    eprintln!("{error}");
-                    exit(65);
-                }
-                _ => {}
+            if let Err(error) = traverser.run(&statements) {
+                eprintln!("{error}");
+                exit(65);
             }
 
-            let mut interpreter = lox::ci::Interpreter::new(statements);
+            let options = lox::ci::RuntimeOptions {
+                seed,
+                no_filesystem: no_fs,
+                args,
+            };
+            let mut interpreter = lox::ci::Interpreter::with_options(statements, options);
             interpreter.run();
         }
     }
@@ -1,27 +1,38 @@
 use miette::LabeledSpan;
 use std::collections::HashMap;
 
+use crate::BigInt;
+
 #[derive(Debug, Clone)]
 #[rustfmt::skip] 
 #[allow(non_camel_case_types)]
 pub enum TokenType {
     // Single-character tokens.
-    LEFT_PAREN, RIGHT_PAREN, LEFT_BRACE, RIGHT_BRACE,
-    COMMA, DOT, MINUS, PLUS, SEMICOLON, SLASH, STAR,
+    LEFT_PAREN, RIGHT_PAREN, LEFT_BRACE, RIGHT_BRACE, LEFT_BRACKET, RIGHT_BRACKET,
+    COMMA, DOT, MINUS, PLUS, SEMICOLON, SLASH, STAR, PERCENT,
+    AMPERSAND, PIPE, CARET, TILDE, COLON,
 
     // One or two character tokens.
     BANG, BANG_EQUAL,
     EQUAL, EQUAL_EQUAL,
     GREATER, GREATER_EQUAL,
-    LESS, LESS_EQUAL,
+    LESS, LESS_EQUAL, LESS_LESS,
+    GREATER_GREATER, TILDE_SLASH, STAR_STAR,
+    PLUS_EQUAL, MINUS_EQUAL, STAR_EQUAL, SLASH_EQUAL, PERCENT_EQUAL,
+    QUESTION, QUESTION_QUESTION, QUESTION_DOT, DOT_DOT_DOT,
 
     // Literals.
-    IDENTIFIER, STRING(String), NUMBER(f64),
+    IDENTIFIER, STRING(String), NUMBER(f64), INTEGER(i64), BIG_INTEGER(BigInt),
+    // A string segment followed by an embedded `${expression}`, the string goes on after it.
+    INTERPOLATION(String),
 
     // Keywords.
-    AND, CLASS, ELSE, FALSE, FUN, FOR, IF, NIL, OR,
+    AND, CLASS, CONST, ELSE, FALSE, FINAL, FUN, FOR, IF, NIL, OR,
     PRINT, RETURN, SUPER, THIS, TRUE, VAR, WHILE,
 
+    // Trivia, only emitted by `Scanner::with_trivia`.
+    COMMENT, NEWLINE,
+
     EOF
 }
 
@@ -30,6 +41,8 @@ pub struct Token<'de> {
     pub token_type: TokenType,
     pub lexeme: &'de str,
     pub line: usize,
+    /// Column of the first character of the lexeme, starting at 1
+    pub column: usize,
 }
 
 impl<'de> Token<'de> {
@@ -38,6 +51,7 @@ impl<'de> Token<'de> {
             token_type,
             lexeme,
             line,
+            column: 0,
         }
     }
 }
@@ -45,7 +59,7 @@ impl<'de> Token<'de> {
 impl<'de> std::fmt::Display for Token<'de> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let literal = match &self.token_type {
-            TokenType::STRING(literal) => literal,
+            TokenType::STRING(literal) | TokenType::INTERPOLATION(literal) => literal,
             TokenType::NUMBER(literal) => {
                 if literal.fract() == 0.0 {
                     &format!("{:.1}", literal)
@@ -53,9 +67,15 @@ impl<'de> std::fmt::Display for Token<'de> {
                     &format!("{}", literal)
                 }
             }
+            TokenType::INTEGER(literal) => &format!("{}.0", literal),
+            TokenType::BIG_INTEGER(literal) => &format!("{}.0", literal),
             _ => "null",
         };
-        let type_name = format!("{:?}", self.token_type);
+        let type_name = match &self.token_type {
+            // Integers are listed as numbers, like every number literal always was
+            TokenType::INTEGER(_) | TokenType::BIG_INTEGER(_) => "NUMBER".to_string(),
+            token_type => format!("{:?}", token_type),
+        };
         let type_name = type_name.split('(').next().unwrap();
         write!(f, "{} {} {}", type_name, self.lexeme, literal)
     }
@@ -67,17 +87,38 @@ pub struct Scanner<'de> {
     current: usize,
     line: usize,
     eof: bool,
+    trivia: bool,
+    // Open braces of each `${` interpolation being scanned, innermost last
+    interpolations: Vec<usize>,
 }
 
 impl<'de> Scanner<'de> {
     pub fn new(source: &'de str) -> Self {
-        Scanner {
-            source: source,
+        let mut scanner = Scanner {
+            source,
             rest: source,
             current: 0,
             line: 1,
             eof: false,
+            trivia: false,
+            interpolations: vec![],
+        };
+
+        // Skipping a shebang line (`#!/usr/bin/env lox`), the line break itself is still scanned
+        if source.starts_with("#!") {
+            let shebang_len = source.chars().take_while(|&c| c != '\n').count();
+            let _ = scanner.advance_n(shebang_len);
         }
+
+        scanner
+    }
+
+    /// Scanner that also emits comments and line breaks, for tools that have to preserve them.
+    pub fn with_trivia(source: &'de str) -> Self {
+        let mut scanner = Self::new(source);
+        scanner.trivia = true;
+
+        scanner
     }
 
     fn get_keyword(&self, lemexe: &'de str) -> TokenType {
@@ -85,8 +126,10 @@ impl<'de> Scanner<'de> {
         [
             ("and", TokenType::AND),
             ("class", TokenType::CLASS),
+            ("const", TokenType::CONST),
             ("else", TokenType::ELSE),
             ("false", TokenType::FALSE),
+            ("final", TokenType::FINAL),
             ("fun", TokenType::FUN),
             ("for", TokenType::FOR),
             ("if", TokenType::IF),
@@ -103,7 +146,7 @@ impl<'de> Scanner<'de> {
         .into_iter()
         .collect::<HashMap<&'static str, TokenType>>()
         .get(lemexe)
-        .map(|x| x.clone())
+        .cloned()
         .unwrap_or(TokenType::IDENTIFIER)
     }
 
@@ -124,10 +167,287 @@ impl<'de> Scanner<'de> {
         lexeme
     }
 
+    /// Column of a lexeme that was just scanned, counted in characters from the start of its line.
+    fn column_of(&self, lexeme: &'de str) -> usize {
+        let start = self.source.len() - self.rest.len() - lexeme.len();
+
+        self.source[..start]
+            .chars()
+            .rev()
+            .take_while(|&c| c != '\n')
+            .count()
+            + 1
+    }
+
     fn peek_rest_at(&self, pos: usize) -> Option<char> {
         self.rest.chars().nth(pos)
     }
 
+    fn error(&self, offset: usize, len: usize, message: String) -> miette::Error {
+        miette::miette! {
+            labels = vec![LabeledSpan::at(offset..offset + len, "here")],
+            "{message}",
+        }
+        .with_source_code(self.source.to_string())
+    }
+
+    /// Scans a `"..."` literal, the token holds the decoded value and the lexeme the original text.
+    /// It also scans the rest of an interpolated string, starting at the `}` of an embedded expression.
+    fn string(&mut self) -> Option<miette::Result<Token<'de>, miette::Error>> {
+        let offset = self.source.len() - self.rest.len();
+        let mut chars = self.rest.chars().skip(1).peekable();
+        // Length of the literal so far, in chars and bytes
+        let mut cur_len = 1;
+        let mut bytes_len = 1;
+        let mut literal = String::new();
+        // The first invalid escape, reported once the whole literal is consumed
+        let mut error = None;
+
+        loop {
+            let Some(c) = chars.next() else {
+                let _ = self.advance_n(cur_len);
+                let line = self.line;
+
+                return Some(Err(self.error(
+                    self.source.len(),
+                    0,
+                    format!("[line {line}] Error: Unterminated string."),
+                )));
+            };
+            let escape_offset = offset + bytes_len;
+            cur_len += 1;
+            bytes_len += c.len_utf8();
+
+            match c {
+                '"' => break,
+                '$' if chars.peek() == Some(&'{') => {
+                    let _ = chars.next();
+                    cur_len += 1;
+
+                    let lexeme = self.advance_n(cur_len);
+                    self.interpolations.push(0);
+                    if let Some((offset, len, kind, escape, line)) = error {
+                        return Some(Err(self.error(
+                            offset,
+                            len,
+                            format!("[line {line}] Error: {kind} '{escape}'."),
+                        )));
+                    }
+
+                    return Some(Ok(Token::new(
+                        TokenType::INTERPOLATION(literal),
+                        lexeme,
+                        self.line,
+                    )));
+                }
+                '\n' => {
+                    self.line += 1;
+                    literal.push(c);
+                }
+                '\\' => {
+                    let mut escape = String::from('\\');
+                    let decoded = match chars.next() {
+                        Some(c) => {
+                            cur_len += 1;
+                            bytes_len += c.len_utf8();
+                            escape.push(c);
+
+                            match c {
+                                'n' => Some('\n'),
+                                't' => Some('\t'),
+                                'r' => Some('\r'),
+                                '\\' => Some('\\'),
+                                '"' => Some('"'),
+                                '$' => Some('$'),
+                                'u' => {
+                                    // `\u{XXXX}` with 1 to 6 hex digits
+                                    let mut digits = String::new();
+                                    let mut closed = false;
+                                    if chars.next_if_eq(&'{').is_some() {
+                                        escape.push('{');
+                                        cur_len += 1;
+                                        bytes_len += 1;
+
+                                        while let Some(c) = chars
+                                            .next_if(|&c| c.is_ascii_hexdigit() && digits.len() < 6)
+                                        {
+                                            digits.push(c);
+                                            escape.push(c);
+                                            cur_len += 1;
+                                            bytes_len += 1;
+                                        }
+                                        if chars.next_if_eq(&'}').is_some() {
+                                            escape.push('}');
+                                            cur_len += 1;
+                                            bytes_len += 1;
+                                            closed = true;
+                                        }
+                                    }
+
+                                    u32::from_str_radix(&digits, 16)
+                                        .ok()
+                                        .filter(|_| closed)
+                                        .and_then(char::from_u32)
+                                }
+                                _ => None,
+                            }
+                        }
+                        None => None,
+                    };
+
+                    match decoded {
+                        Some(c) => literal.push(c),
+                        None if error.is_none() => {
+                            let kind = if escape.starts_with("\\u") {
+                                "Invalid unicode escape"
+                            } else {
+                                "Unknown escape sequence"
+                            };
+                            error = Some((escape_offset, escape.len(), kind, escape, self.line));
+                        }
+                        None => {}
+                    }
+                }
+                c => literal.push(c),
+            }
+        }
+
+        let lexeme = self.advance_n(cur_len);
+        if let Some((offset, len, kind, escape, line)) = error {
+            return Some(Err(self.error(
+                offset,
+                len,
+                format!("[line {line}] Error: {kind} '{escape}'."),
+            )));
+        }
+
+        return Some(Ok(Token::new(
+            TokenType::STRING(literal),
+            lexeme,
+            self.line,
+        )));
+    }
+
+    /// Scans a `r"..."` literal, its content is taken verbatim, without escapes.
+    fn raw_string(&mut self) -> Option<miette::Result<Token<'de>, miette::Error>> {
+        let Some(content_len) = self.rest[2..].find('"') else {
+            let _ = self.advance_n(self.rest.chars().count());
+            let line = self.line;
+
+            return Some(Err(self.error(
+                self.source.len(),
+                0,
+                format!("[line {line}] Error: Unterminated string."),
+            )));
+        };
+
+        let content = &self.rest[2..2 + content_len];
+        self.line += content.matches('\n').count();
+        let lexeme = self.advance_n(content.chars().count() + 3);
+
+        return Some(Ok(Token::new(
+            TokenType::STRING(content.to_string()),
+            lexeme,
+            self.line,
+        )));
+    }
+
+    /// Scans a number literal: decimal with an optional fraction and exponent,
+    /// or an integer with a `0x`, `0b` or `0o` prefix. Digits can be separated with `_`.
+    /// Literals without fraction and exponent are integers, big integers if they don't fit in 64 bits.
+    fn number(&mut self) -> Option<miette::Result<Token<'de>, miette::Error>> {
+        fn digits_end(bytes: &[u8], start: usize) -> usize {
+            start
+                + bytes[start.min(bytes.len())..]
+                    .iter()
+                    .take_while(|&&b| b.is_ascii_digit() || b == b'_')
+                    .count()
+        }
+
+        // `_` is only allowed between two digits
+        fn separators_valid(digits: &str, radix: u32) -> bool {
+            let chars = digits.chars().collect::<Vec<_>>();
+            return chars.iter().enumerate().all(|(i, &c)| {
+                c != '_'
+                    || (i > 0
+                        && chars[i - 1].is_digit(radix)
+                        && chars.get(i + 1).is_some_and(|c| c.is_digit(radix)))
+            });
+        }
+
+        let bytes = self.rest.as_bytes();
+        let offset = self.source.len() - self.rest.len();
+        let radix = match bytes.get(..2) {
+            Some(b"0x" | b"0X") => 16,
+            Some(b"0b" | b"0B") => 2,
+            Some(b"0o" | b"0O") => 8,
+            _ => 10,
+        };
+
+        let (len, literal) = if radix != 10 {
+            // The whole alphanumeric run is the literal, so `0b102` is reported as a whole
+            let len = 2 + bytes[2..]
+                .iter()
+                .take_while(|&&b| b.is_ascii_alphanumeric() || b == b'_')
+                .count();
+            let digits = &self.rest[2..len];
+
+            let literal = if !digits.is_empty() && separators_valid(digits, radix) {
+                let digits = digits.replace('_', "");
+                match i64::from_str_radix(&digits, radix) {
+                    Ok(integer) => Some(TokenType::INTEGER(integer)),
+                    Err(_) => BigInt::parse_radix(&digits, radix).map(TokenType::BIG_INTEGER),
+                }
+            } else {
+                None
+            };
+
+            (len, literal)
+        } else {
+            let mut len = digits_end(bytes, 0);
+            if bytes.get(len) == Some(&b'.') && bytes.get(len + 1).is_some_and(u8::is_ascii_digit) {
+                len = digits_end(bytes, len + 1);
+            }
+
+            let mut exponent_valid = true;
+            if matches!(bytes.get(len), Some(b'e' | b'E')) {
+                let mut exponent = len + 1;
+                if matches!(bytes.get(exponent), Some(b'+' | b'-')) {
+                    exponent += 1;
+                }
+                len = digits_end(bytes, exponent);
+                exponent_valid = len > exponent && bytes[exponent] != b'_';
+            }
+
+            let lexeme = &self.rest[..len];
+            let literal = if exponent_valid && separators_valid(lexeme, 10) {
+                let lexeme = lexeme.replace('_', "");
+                if !lexeme.bytes().all(|b| b.is_ascii_digit()) {
+                    lexeme.parse::<f64>().ok().map(TokenType::NUMBER)
+                } else if let Ok(integer) = lexeme.parse::<i64>() {
+                    Some(TokenType::INTEGER(integer))
+                } else {
+                    BigInt::parse_radix(&lexeme, 10).map(TokenType::BIG_INTEGER)
+                }
+            } else {
+                None
+            };
+
+            (len, literal)
+        };
+
+        let lexeme = self.advance_n(len);
+        let line = self.line;
+        return match literal {
+            Some(literal) => Some(Ok(Token::new(literal, lexeme, line))),
+            None => Some(Err(self.error(
+                offset,
+                len,
+                format!("[line {line}] Error: Malformed number literal '{lexeme}'."),
+            ))),
+        };
+    }
+
     fn scan_token(&mut self) -> Option<miette::Result<Token<'de>, miette::Error>> {
         fn token<'de>(
             token_type: TokenType,
@@ -137,12 +457,8 @@ impl<'de> Scanner<'de> {
             Some(Ok(Token::<'de>::new(token_type, lexeme, line)))
         }
 
-        'scan_loop: loop {
-            let cur = if let Some(cur) = self.peek_rest_at(0) {
-                cur
-            } else {
-                return None;
-            };
+        loop {
+            let cur = self.peek_rest_at(0)?;
 
             match cur {
                 // Meaningless characters.
@@ -150,131 +466,178 @@ impl<'de> Scanner<'de> {
                     let _ = self.advance_n(1);
                 }
                 '\n' => {
+                    let line = self.line;
                     self.line += 1;
-                    let _ = self.advance_n(1);
+                    let lexeme = self.advance_n(1);
+
+                    if self.trivia {
+                        return token(TokenType::NEWLINE, lexeme, line);
+                    }
                 }
                 // Single-character tokens.
                 '(' => return token(TokenType::LEFT_PAREN, self.advance_n(1), self.line),
                 ')' => return token(TokenType::RIGHT_PAREN, self.advance_n(1), self.line),
-                '{' => return token(TokenType::LEFT_BRACE, self.advance_n(1), self.line),
-                '}' => return token(TokenType::RIGHT_BRACE, self.advance_n(1), self.line),
+                '{' => {
+                    if let Some(braces) = self.interpolations.last_mut() {
+                        *braces += 1;
+                    }
+                    return token(TokenType::LEFT_BRACE, self.advance_n(1), self.line);
+                }
+                '}' => match self.interpolations.last_mut() {
+                    // End of an embedded expression, the string goes on
+                    Some(0) => {
+                        let _ = self.interpolations.pop();
+                        return self.string();
+                    }
+                    Some(braces) => {
+                        *braces -= 1;
+                        return token(TokenType::RIGHT_BRACE, self.advance_n(1), self.line);
+                    }
+                    None => return token(TokenType::RIGHT_BRACE, self.advance_n(1), self.line),
+                },
+                '[' => return token(TokenType::LEFT_BRACKET, self.advance_n(1), self.line),
+                ']' => return token(TokenType::RIGHT_BRACKET, self.advance_n(1), self.line),
                 ',' => return token(TokenType::COMMA, self.advance_n(1), self.line),
-                '.' => return token(TokenType::DOT, self.advance_n(1), self.line),
-                '-' => return token(TokenType::MINUS, self.advance_n(1), self.line),
-                '+' => return token(TokenType::PLUS, self.advance_n(1), self.line),
+                '.' => match (self.peek_rest_at(1), self.peek_rest_at(2)) {
+                    (Some('.'), Some('.')) => {
+                        return token(TokenType::DOT_DOT_DOT, self.advance_n(3), self.line)
+                    }
+                    _ => return token(TokenType::DOT, self.advance_n(1), self.line),
+                },
+                '-' => match self.peek_rest_at(1) {
+                    Some('=') => {
+                        return token(TokenType::MINUS_EQUAL, self.advance_n(2), self.line)
+                    }
+                    _ => return token(TokenType::MINUS, self.advance_n(1), self.line),
+                },
+                '+' => match self.peek_rest_at(1) {
+                    Some('=') => return token(TokenType::PLUS_EQUAL, self.advance_n(2), self.line),
+                    _ => return token(TokenType::PLUS, self.advance_n(1), self.line),
+                },
                 ';' => return token(TokenType::SEMICOLON, self.advance_n(1), self.line),
-                '*' => return token(TokenType::STAR, self.advance_n(1), self.line),
+                '*' => match self.peek_rest_at(1) {
+                    Some('*') => return token(TokenType::STAR_STAR, self.advance_n(2), self.line),
+                    Some('=') => return token(TokenType::STAR_EQUAL, self.advance_n(2), self.line),
+                    _ => return token(TokenType::STAR, self.advance_n(1), self.line),
+                },
+                '%' => match self.peek_rest_at(1) {
+                    Some('=') => {
+                        return token(TokenType::PERCENT_EQUAL, self.advance_n(2), self.line)
+                    }
+                    _ => return token(TokenType::PERCENT, self.advance_n(1), self.line),
+                },
+                '&' => return token(TokenType::AMPERSAND, self.advance_n(1), self.line),
+                '|' => return token(TokenType::PIPE, self.advance_n(1), self.line),
+                '^' => return token(TokenType::CARET, self.advance_n(1), self.line),
+                ':' => return token(TokenType::COLON, self.advance_n(1), self.line),
                 '/' => match self.peek_rest_at(1) {
-                    Some(next) if next == '/' => loop {
-                        match self.peek_rest_at(0) {
-                            Some(cur) if cur == '\n' => {
-                                continue 'scan_loop;
-                            }
-                            Some(_) => {
-                                // Comment content
-                                let _ = self.advance_n(1);
+                    Some('/') => {
+                        let mut cur_len = 2;
+                        // Comment content
+                        while !matches!(self.peek_rest_at(cur_len), Some('\n') | None) {
+                            cur_len += 1;
+                        }
+
+                        let lexeme = self.advance_n(cur_len);
+                        if self.trivia {
+                            return token(TokenType::COMMENT, lexeme, self.line);
+                        }
+                    }
+                    Some('*') => {
+                        let line = self.line;
+                        let offset = self.source.len() - self.rest.len();
+
+                        // Block comments nest, `/* a /* b */ c */` is a single comment
+                        let mut chars = self.rest.chars().skip(2).peekable();
+                        let mut cur_len = 2;
+                        let mut depth = 1;
+                        let mut lines = 0;
+                        while depth > 0 {
+                            match chars.next() {
+                                Some('/') if chars.peek() == Some(&'*') => {
+                                    let _ = chars.next();
+                                    cur_len += 2;
+                                    depth += 1;
+                                }
+                                Some('*') if chars.peek() == Some(&'/') => {
+                                    let _ = chars.next();
+                                    cur_len += 2;
+                                    depth -= 1;
+                                }
+                                Some(c) => {
+                                    if c == '\n' {
+                                        lines += 1;
+                                    }
+                                    cur_len += 1;
+                                }
+                                None => {
+                                    let _ = self.advance_n(cur_len);
+                                    self.line += lines;
+
+                                    return Some(Err(miette::miette! {
+                                        labels = vec![LabeledSpan::at(offset..offset + 2, "opened here")],
+                                        "[line {line}] Error: Unterminated block comment.",
+                                    }
+                                    .with_source_code(self.source.to_string())));
+                                }
                             }
-                            None => continue 'scan_loop,
                         }
-                    },
+
+                        let lexeme = self.advance_n(cur_len);
+                        self.line += lines;
+                        if self.trivia {
+                            return token(TokenType::COMMENT, lexeme, line);
+                        }
+                    }
+                    Some('=') => {
+                        return token(TokenType::SLASH_EQUAL, self.advance_n(2), self.line)
+                    }
                     _ => return token(TokenType::SLASH, self.advance_n(1), self.line),
                 },
                 // One or two character tokens.
                 '=' => match self.peek_rest_at(1) {
-                    Some(next) if next == '=' => {
+                    Some('=') => {
                         return token(TokenType::EQUAL_EQUAL, self.advance_n(2), self.line);
                     }
                     _ => return token(TokenType::EQUAL, self.advance_n(1), self.line),
                 },
                 '!' => match self.peek_rest_at(1) {
-                    Some(next) if next == '=' => {
-                        return token(TokenType::BANG_EQUAL, self.advance_n(2), self.line)
-                    }
+                    Some('=') => return token(TokenType::BANG_EQUAL, self.advance_n(2), self.line),
                     _ => return token(TokenType::BANG, self.advance_n(1), self.line),
                 },
                 '<' => match self.peek_rest_at(1) {
-                    Some(next) if next == '=' => {
-                        return token(TokenType::LESS_EQUAL, self.advance_n(2), self.line)
-                    }
+                    Some('=') => return token(TokenType::LESS_EQUAL, self.advance_n(2), self.line),
+                    Some('<') => return token(TokenType::LESS_LESS, self.advance_n(2), self.line),
                     _ => return token(TokenType::LESS, self.advance_n(1), self.line),
                 },
                 '>' => match self.peek_rest_at(1) {
-                    Some(next) if next == '=' => {
+                    Some('=') => {
                         return token(TokenType::GREATER_EQUAL, self.advance_n(2), self.line)
                     }
+                    Some('>') => {
+                        return token(TokenType::GREATER_GREATER, self.advance_n(2), self.line)
+                    }
                     _ => return token(TokenType::GREATER, self.advance_n(1), self.line),
                 },
-                // Literals.
-                '\"' => {
-                    let mut cur_len = 1;
-
-                    loop {
-                        match self.peek_rest_at(cur_len) {
-                            Some(cur) if cur == '\n' => {
-                                self.line += 1;
-                                cur_len += 1;
-                            }
-                            Some(cur) if cur == '\"' => {
-                                cur_len += 1;
-
-                                let lexeme = self.advance_n(cur_len);
-                                // Can cheat with trim
-                                let literal = lexeme
-                                    .chars()
-                                    .skip(1)
-                                    .take_while(|&c| c != '\"')
-                                    .collect::<String>();
-
-                                return token(TokenType::STRING(literal), lexeme, self.line);
-                            }
-                            Some(_) => {
-                                cur_len += 1;
-                            }
-                            None => {
-                                let _ = self.advance_n(cur_len);
-                                let line = self.line;
-
-                                return Some(Err(miette::miette! {
-                                    labels = vec![LabeledSpan::at_offset(self.current, "here")],
-                                    "[line {line}] Error: Unterminated string.",
-                                }
-                                .with_source_code(self.source.to_string())));
-                            }
-                        }
+                '~' => match self.peek_rest_at(1) {
+                    Some('/') => {
+                        return token(TokenType::TILDE_SLASH, self.advance_n(2), self.line)
                     }
-                }
-                '0'..='9' => {
-                    let mut cur_len = 0;
-                    let mut seen_dot = false;
-
-                    fn token_number<'de>(
-                        lexeme: &'de str,
-                        line: usize,
-                    ) -> Option<miette::Result<Token<'de>, miette::Error>> {
-                        let literal = lexeme.parse::<f64>().unwrap();
-
-                        return token(TokenType::NUMBER(literal), lexeme, line);
+                    _ => return token(TokenType::TILDE, self.advance_n(1), self.line),
+                },
+                '?' => match self.peek_rest_at(1) {
+                    Some('?') => {
+                        return token(TokenType::QUESTION_QUESTION, self.advance_n(2), self.line)
                     }
-
-                    loop {
-                        match self.peek_rest_at(cur_len) {
-                            Some(c) if c.is_digit(10) => {
-                                cur_len += 1;
-                            }
-                            Some(c) if c == '.' && !seen_dot => {
-                                seen_dot = true;
-
-                                match self.peek_rest_at(cur_len + 1) {
-                                    Some(next_c) if next_c.is_digit(10) => {
-                                        cur_len += 1;
-                                    }
-                                    _ => return token_number(self.advance_n(cur_len), self.line),
-                                };
-                            }
-                            _ => return token_number(self.advance_n(cur_len), self.line),
-                        }
+                    Some('.') => {
+                        return token(TokenType::QUESTION_DOT, self.advance_n(2), self.line)
                     }
-                }
+                    _ => return token(TokenType::QUESTION, self.advance_n(1), self.line),
+                },
+                // Literals.
+                '\"' => return self.string(),
+                'r' if self.peek_rest_at(1) == Some('\"') => return self.raw_string(),
+                '0'..='9' => return self.number(),
                 'a'..='z' | 'A'..='Z' | '_' => {
                     let mut cur_len = 0;
 
@@ -290,7 +653,7 @@ impl<'de> Scanner<'de> {
                         }
                     }
                 }
-                lexeme @ _ => {
+                lexeme => {
                     let _ = self.advance_n(1);
                     let line = self.line;
 
@@ -310,13 +673,29 @@ impl<'de> Iterator for Scanner<'de> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let token = self.scan_token();
-        if token.is_some() {
-            return token;
+        if let Some(token) = token {
+            return Some(token.map(|mut token| {
+                token.column = self.column_of(token.lexeme);
+                token
+            }));
         } else {
+            if !self.interpolations.is_empty() {
+                self.interpolations.clear();
+                let line = self.line;
+
+                return Some(Err(self.error(
+                    self.source.len(),
+                    0,
+                    format!("[line {line}] Error: Unterminated string interpolation."),
+                )));
+            }
+
             if !self.eof {
                 self.eof = true;
 
-                return Some(Ok(Token::new(TokenType::EOF, "", self.line)));
+                let mut token = Token::new(TokenType::EOF, "", self.line);
+                token.column = self.column_of(token.lexeme);
+                return Some(Ok(token));
             } else {
                 return None;
             }
@@ -163,4 +163,84 @@ mod classes {
     fn return_within_constructors_4() {
         run_case("tests/test_cases/run/Classes/Return within constructors - 4");
     }
+
+    #[test]
+    fn compound_assignment_1() {
+        run_case("tests/test_cases/run/Classes/Compound Assignment - 1");
+    }
+
+    #[test]
+    fn compound_assignment_2() {
+        run_case("tests/test_cases/run/Classes/Compound Assignment - 2");
+    }
+
+    #[test]
+    fn optional_chaining_1() {
+        run_case("tests/test_cases/run/Classes/Optional chaining - 1");
+    }
+
+    #[test]
+    fn optional_chaining_2() {
+        run_case("tests/test_cases/run/Classes/Optional chaining - 2");
+    }
+
+    #[test]
+    fn optional_chaining_3() {
+        run_case("tests/test_cases/run/Classes/Optional chaining - 3");
+    }
+
+    #[test]
+    fn optional_chaining_4() {
+        run_case("tests/test_cases/run/Classes/Optional chaining - 4");
+    }
+
+    #[test]
+    fn optional_chaining_5() {
+        run_case("tests/test_cases/run/Classes/Optional chaining - 5");
+    }
+
+    #[test]
+    fn method_parameters_1() {
+        run_case("tests/test_cases/run/Classes/Method parameters - 1");
+    }
+
+    #[test]
+    fn method_parameters_2() {
+        run_case("tests/test_cases/run/Classes/Method parameters - 2");
+    }
+
+    #[test]
+    fn initializer_arity_1() {
+        run_case("tests/test_cases/run/Classes/Initializer arity - 1");
+    }
+
+    #[test]
+    fn initializer_arity_2() {
+        run_case("tests/test_cases/run/Classes/Initializer arity - 2");
+    }
+
+    #[test]
+    fn initializer_arity_3() {
+        run_case("tests/test_cases/run/Classes/Initializer arity - 3");
+    }
+
+    #[test]
+    fn final_fields_1() {
+        run_case("tests/test_cases/run/Classes/Final fields - 1");
+    }
+
+    #[test]
+    fn final_fields_2() {
+        run_case("tests/test_cases/run/Classes/Final fields - 2");
+    }
+
+    #[test]
+    fn final_fields_3() {
+        run_case("tests/test_cases/run/Classes/Final fields - 3");
+    }
+
+    #[test]
+    fn final_fields_4() {
+        run_case("tests/test_cases/run/Classes/Final fields - 4");
+    }
 }
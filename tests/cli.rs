@@ -0,0 +1,136 @@
+mod helper;
+
+#[cfg(test)]
+mod cli {
+    use crate::helper::run_case;
+
+    #[test]
+    fn input_1() {
+        run_case("tests/test_cases/cli/Input - 1");
+    }
+
+    #[test]
+    fn input_2() {
+        run_case("tests/test_cases/cli/Input - 2");
+    }
+
+    #[test]
+    fn input_3() {
+        run_case("tests/test_cases/cli/Input - 3");
+    }
+    #[test]
+    fn input_4() {
+        run_case("tests/test_cases/cli/Input - 4");
+    }
+
+    #[test]
+    fn input_5() {
+        run_case("tests/test_cases/cli/Input - 5");
+    }
+
+    #[test]
+    fn input_6() {
+        run_case("tests/test_cases/cli/Input - 6");
+    }
+
+    #[test]
+    fn format_1() {
+        run_case("tests/test_cases/cli/Format - 1");
+    }
+
+    #[test]
+    fn format_2() {
+        run_case("tests/test_cases/cli/Format - 2");
+    }
+
+    #[test]
+    fn format_3() {
+        run_case("tests/test_cases/cli/Format - 3");
+    }
+    #[test]
+    fn programs_1() {
+        run_case("tests/test_cases/cli/Programs - 1");
+    }
+
+    #[test]
+    fn programs_2() {
+        run_case("tests/test_cases/cli/Programs - 2");
+    }
+
+    #[test]
+    fn programs_3() {
+        run_case("tests/test_cases/cli/Programs - 3");
+    }
+
+    #[test]
+    fn programs_4() {
+        run_case("tests/test_cases/cli/Programs - 4");
+    }
+
+    #[test]
+    fn programs_5() {
+        run_case("tests/test_cases/cli/Programs - 5");
+    }
+
+    #[test]
+    fn programs_6() {
+        run_case("tests/test_cases/cli/Programs - 6");
+    }
+
+    #[test]
+    fn programs_7() {
+        run_case("tests/test_cases/cli/Programs - 7");
+    }
+
+    #[test]
+    fn programs_8() {
+        run_case("tests/test_cases/cli/Programs - 8");
+    }
+    #[test]
+    fn dot_1() {
+        run_case("tests/test_cases/cli/Dot - 1");
+    }
+
+    #[test]
+    fn dot_2() {
+        run_case("tests/test_cases/cli/Dot - 2");
+    }
+    #[test]
+    fn fmt_1() {
+        run_case("tests/test_cases/cli/Fmt - 1");
+    }
+
+    #[test]
+    fn fmt_2() {
+        run_case("tests/test_cases/cli/Fmt - 2");
+    }
+
+    #[test]
+    fn fmt_3() {
+        run_case("tests/test_cases/cli/Fmt - 3");
+    }
+
+    #[test]
+    fn fmt_4() {
+        run_case("tests/test_cases/cli/Fmt - 4");
+    }
+
+    #[test]
+    fn fmt_5() {
+        run_case("tests/test_cases/cli/Fmt - 5");
+    }
+    #[test]
+    fn format_4() {
+        run_case("tests/test_cases/cli/Format - 4");
+    }
+
+    #[test]
+    fn fmt_6() {
+        run_case("tests/test_cases/cli/Fmt - 6");
+    }
+
+    #[test]
+    fn numbers_1() {
+        run_case("tests/test_cases/cli/Numbers - 1");
+    }
+}
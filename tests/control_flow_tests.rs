@@ -183,4 +183,39 @@ mod control_flow {
     fn syntactic_errors_4() {
         run_case("tests/test_cases/run/Control Flow/Syntactic errors - 4");
     }
+
+    #[test]
+    fn conditional_operator_1() {
+        run_case("tests/test_cases/run/Control Flow/Conditional operator - 1");
+    }
+
+    #[test]
+    fn conditional_operator_2() {
+        run_case("tests/test_cases/run/Control Flow/Conditional operator - 2");
+    }
+
+    #[test]
+    fn conditional_operator_3() {
+        run_case("tests/test_cases/run/Control Flow/Conditional operator - 3");
+    }
+
+    #[test]
+    fn conditional_operator_4() {
+        run_case("tests/test_cases/run/Control Flow/Conditional operator - 4");
+    }
+
+    #[test]
+    fn conditional_operator_5() {
+        run_case("tests/test_cases/run/Control Flow/Conditional operator - 5");
+    }
+
+    #[test]
+    fn nil_coalescing_operator_1() {
+        run_case("tests/test_cases/run/Control Flow/Nil coalescing operator - 1");
+    }
+
+    #[test]
+    fn nil_coalescing_operator_2() {
+        run_case("tests/test_cases/run/Control Flow/Nil coalescing operator - 2");
+    }
 }
@@ -163,4 +163,99 @@ mod functions {
     fn closures_4() {
         run_case("tests/test_cases/run/Functions/Closures - 4");
     }
+
+    #[test]
+    fn default_parameters_1() {
+        run_case("tests/test_cases/run/Functions/Default parameters - 1");
+    }
+
+    #[test]
+    fn default_parameters_2() {
+        run_case("tests/test_cases/run/Functions/Default parameters - 2");
+    }
+
+    #[test]
+    fn default_parameters_3() {
+        run_case("tests/test_cases/run/Functions/Default parameters - 3");
+    }
+
+    #[test]
+    fn default_parameters_4() {
+        run_case("tests/test_cases/run/Functions/Default parameters - 4");
+    }
+
+    #[test]
+    fn rest_parameters_1() {
+        run_case("tests/test_cases/run/Functions/Rest parameters - 1");
+    }
+
+    #[test]
+    fn rest_parameters_2() {
+        run_case("tests/test_cases/run/Functions/Rest parameters - 2");
+    }
+
+    #[test]
+    fn rest_parameters_3() {
+        run_case("tests/test_cases/run/Functions/Rest parameters - 3");
+    }
+
+    #[test]
+    fn named_arguments_1() {
+        run_case("tests/test_cases/run/Functions/Named arguments - 1");
+    }
+
+    #[test]
+    fn named_arguments_2() {
+        run_case("tests/test_cases/run/Functions/Named arguments - 2");
+    }
+
+    #[test]
+    fn named_arguments_3() {
+        run_case("tests/test_cases/run/Functions/Named arguments - 3");
+    }
+
+    #[test]
+    fn named_arguments_4() {
+        run_case("tests/test_cases/run/Functions/Named arguments - 4");
+    }
+
+    #[test]
+    fn named_arguments_5() {
+        run_case("tests/test_cases/run/Functions/Named arguments - 5");
+    }
+
+    #[test]
+    fn named_arguments_6() {
+        run_case("tests/test_cases/run/Functions/Named arguments - 6");
+    }
+
+    #[test]
+    fn named_arguments_7() {
+        run_case("tests/test_cases/run/Functions/Named arguments - 7");
+    }
+
+    #[test]
+    fn argument_limits_1() {
+        run_case("tests/test_cases/run/Functions/Argument limits - 1");
+    }
+
+    #[test]
+    fn argument_limits_2() {
+        run_case("tests/test_cases/run/Functions/Argument limits - 2");
+    }
+
+    #[test]
+    fn argument_limits_3() {
+        run_case("tests/test_cases/run/Functions/Argument limits - 3");
+    }
+
+    #[test]
+    fn argument_limits_4() {
+        run_case("tests/test_cases/run/Functions/Argument limits - 4");
+    }
+
+    #[test]
+    fn argument_limits_5() {
+        run_case("tests/test_cases/run/Functions/Argument limits - 5");
+    }
 }
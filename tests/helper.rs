@@ -1,18 +1,29 @@
 use std::{
     fs,
+    io::Write,
     process::{Command, Stdio},
 };
 
-fn capture_output(input: &str) -> (String, String, i32) {
-    let output = Command::new("sh")
+fn capture_output(args: &[String], stdin: &str) -> (String, String, i32) {
+    let mut child = Command::new("sh")
         .arg("run_test.sh")
-        .arg("run")
-        .arg(input)
+        .args(args)
+        .stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::piped())
-        .output()
+        .spawn()
         .expect("Failed to execute process");
 
+    {
+        let mut child_stdin = child.stdin.take().expect("Stdin is piped");
+        // The program may exit without reading its input
+        let _ = child_stdin.write_all(stdin.as_bytes());
+    }
+
+    let output = child
+        .wait_with_output()
+        .expect("Failed to wait for process");
+
     let stdout = String::from_utf8_lossy(&output.stdout).to_string();
     let stderr = String::from_utf8_lossy(&output.stderr).to_string();
     let exit_code = output.status.code().unwrap_or(-1);
@@ -20,8 +31,29 @@ fn capture_output(input: &str) -> (String, String, i32) {
     (stdout, stderr, exit_code)
 }
 
+fn command_args(case: &str) -> Vec<String> {
+    // A full command line, one argument per line, where `$CASE` stands for the case directory
+    if let Ok(command) = fs::read_to_string(format!("{case}/command.txt").as_str()) {
+        return command
+            .lines()
+            .map(|arg| arg.replace("$CASE", case))
+            .collect();
+    }
+
+    // Otherwise the program is run, with optional extra arguments, one per line
+    let args = fs::read_to_string(format!("{case}/args.txt").as_str()).unwrap_or_default();
+
+    ["run".to_string(), format!("{case}/program.lox")]
+        .into_iter()
+        .chain(args.lines().map(str::to_string))
+        .collect()
+}
+
 pub fn run_case(case: &str) {
-    let (stdout, stderr, exit_code) = capture_output(format!("{case}/program.lox").as_str());
+    // Optional standard input of the program
+    let stdin = fs::read_to_string(format!("{case}/stdin.txt").as_str()).unwrap_or_default();
+
+    let (stdout, stderr, exit_code) = capture_output(&command_args(case), &stdin);
 
     let expected_out = fs::read_to_string(format!("{case}/out.txt").as_str()).unwrap();
     let expected_err = fs::read_to_string(format!("{case}/err.txt").as_str()).unwrap();
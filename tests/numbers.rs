@@ -0,0 +1,111 @@
+mod helper;
+
+#[cfg(test)]
+mod numbers {
+    use crate::helper::run_case;
+
+    #[test]
+    fn integers_1() {
+        run_case("tests/test_cases/run/Numbers/Integers - 1");
+    }
+
+    #[test]
+    fn integers_2() {
+        run_case("tests/test_cases/run/Numbers/Integers - 2");
+    }
+
+    #[test]
+    fn integers_3() {
+        run_case("tests/test_cases/run/Numbers/Integers - 3");
+    }
+
+    #[test]
+    fn integers_4() {
+        run_case("tests/test_cases/run/Numbers/Integers - 4");
+    }
+
+    #[test]
+    fn integers_5() {
+        run_case("tests/test_cases/run/Numbers/Integers - 5");
+    }
+
+    #[test]
+    fn big_integers_1() {
+        run_case("tests/test_cases/run/Numbers/Big Integers - 1");
+    }
+
+    #[test]
+    fn big_integers_2() {
+        run_case("tests/test_cases/run/Numbers/Big Integers - 2");
+    }
+
+    #[test]
+    fn big_integers_3() {
+        run_case("tests/test_cases/run/Numbers/Big Integers - 3");
+    }
+
+    #[test]
+    fn big_integers_4() {
+        run_case("tests/test_cases/run/Numbers/Big Integers - 4");
+    }
+
+    #[test]
+    fn big_integers_5() {
+        run_case("tests/test_cases/run/Numbers/Big Integers - 5");
+    }
+
+    #[test]
+    fn bitwise_operators_1() {
+        run_case("tests/test_cases/run/Numbers/Bitwise Operators - 1");
+    }
+
+    #[test]
+    fn bitwise_operators_2() {
+        run_case("tests/test_cases/run/Numbers/Bitwise Operators - 2");
+    }
+
+    #[test]
+    fn bitwise_operators_3() {
+        run_case("tests/test_cases/run/Numbers/Bitwise Operators - 3");
+    }
+
+    #[test]
+    fn bitwise_operators_4() {
+        run_case("tests/test_cases/run/Numbers/Bitwise Operators - 4");
+    }
+
+    #[test]
+    fn bitwise_operators_5() {
+        run_case("tests/test_cases/run/Numbers/Bitwise Operators - 5");
+    }
+
+    #[test]
+    fn bitwise_operators_6() {
+        run_case("tests/test_cases/run/Numbers/Bitwise Operators - 6");
+    }
+
+    #[test]
+    fn exponentiation_1() {
+        run_case("tests/test_cases/run/Numbers/Exponentiation - 1");
+    }
+
+    #[test]
+    fn exponentiation_2() {
+        run_case("tests/test_cases/run/Numbers/Exponentiation - 2");
+    }
+
+    #[test]
+    fn exponentiation_3() {
+        run_case("tests/test_cases/run/Numbers/Exponentiation - 3");
+    }
+
+    #[test]
+    fn exponentiation_4() {
+        run_case("tests/test_cases/run/Numbers/Exponentiation - 4");
+    }
+
+    #[test]
+    fn exponentiation_5() {
+        run_case("tests/test_cases/run/Numbers/Exponentiation - 5");
+    }
+}
@@ -0,0 +1,126 @@
+mod helper;
+
+#[cfg(test)]
+mod scanning {
+    use crate::helper::run_case;
+
+    #[test]
+    fn block_comments_1() {
+        run_case("tests/test_cases/run/Scanning/Block Comments - 1");
+    }
+
+    #[test]
+    fn block_comments_2() {
+        run_case("tests/test_cases/run/Scanning/Block Comments - 2");
+    }
+
+    #[test]
+    fn block_comments_3() {
+        run_case("tests/test_cases/run/Scanning/Block Comments - 3");
+    }
+
+    #[test]
+    fn block_comments_4() {
+        run_case("tests/test_cases/run/Scanning/Block Comments - 4");
+    }
+
+    #[test]
+    fn block_comments_5() {
+        run_case("tests/test_cases/run/Scanning/Block Comments - 5");
+    }
+
+    #[test]
+    fn escape_sequences_1() {
+        run_case("tests/test_cases/run/Scanning/Escape Sequences - 1");
+    }
+
+    #[test]
+    fn escape_sequences_2() {
+        run_case("tests/test_cases/run/Scanning/Escape Sequences - 2");
+    }
+
+    #[test]
+    fn escape_sequences_3() {
+        run_case("tests/test_cases/run/Scanning/Escape Sequences - 3");
+    }
+
+    #[test]
+    fn escape_sequences_4() {
+        run_case("tests/test_cases/run/Scanning/Escape Sequences - 4");
+    }
+
+    #[test]
+    fn escape_sequences_5() {
+        run_case("tests/test_cases/run/Scanning/Escape Sequences - 5");
+    }
+
+    #[test]
+    fn raw_strings_1() {
+        run_case("tests/test_cases/run/Scanning/Raw Strings - 1");
+    }
+
+    #[test]
+    fn raw_strings_2() {
+        run_case("tests/test_cases/run/Scanning/Raw Strings - 2");
+    }
+
+    #[test]
+    fn raw_strings_3() {
+        run_case("tests/test_cases/run/Scanning/Raw Strings - 3");
+    }
+
+    #[test]
+    fn interpolation_1() {
+        run_case("tests/test_cases/run/Scanning/Interpolation - 1");
+    }
+
+    #[test]
+    fn interpolation_2() {
+        run_case("tests/test_cases/run/Scanning/Interpolation - 2");
+    }
+
+    #[test]
+    fn interpolation_3() {
+        run_case("tests/test_cases/run/Scanning/Interpolation - 3");
+    }
+
+    #[test]
+    fn interpolation_4() {
+        run_case("tests/test_cases/run/Scanning/Interpolation - 4");
+    }
+
+    #[test]
+    fn interpolation_5() {
+        run_case("tests/test_cases/run/Scanning/Interpolation - 5");
+    }
+
+    #[test]
+    fn numbers_1() {
+        run_case("tests/test_cases/run/Scanning/Numbers - 1");
+    }
+
+    #[test]
+    fn numbers_2() {
+        run_case("tests/test_cases/run/Scanning/Numbers - 2");
+    }
+
+    #[test]
+    fn numbers_3() {
+        run_case("tests/test_cases/run/Scanning/Numbers - 3");
+    }
+
+    #[test]
+    fn numbers_4() {
+        run_case("tests/test_cases/run/Scanning/Numbers - 4");
+    }
+
+    #[test]
+    fn numbers_5() {
+        run_case("tests/test_cases/run/Scanning/Numbers - 5");
+    }
+
+    #[test]
+    fn numbers_6() {
+        run_case("tests/test_cases/run/Scanning/Numbers - 6");
+    }
+}
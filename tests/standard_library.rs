@@ -0,0 +1,181 @@
+mod helper;
+
+#[cfg(test)]
+mod standard_library {
+    use crate::helper::run_case;
+
+    #[test]
+    fn reflection_1() {
+        run_case("tests/test_cases/run/Standard Library/Reflection - 1");
+    }
+
+    #[test]
+    fn reflection_2() {
+        run_case("tests/test_cases/run/Standard Library/Reflection - 2");
+    }
+
+    #[test]
+    fn reflection_3() {
+        run_case("tests/test_cases/run/Standard Library/Reflection - 3");
+    }
+
+    #[test]
+    fn reflection_4() {
+        run_case("tests/test_cases/run/Standard Library/Reflection - 4");
+    }
+
+    #[test]
+    fn strings_1() {
+        run_case("tests/test_cases/run/Standard Library/Strings - 1");
+    }
+
+    #[test]
+    fn strings_2() {
+        run_case("tests/test_cases/run/Standard Library/Strings - 2");
+    }
+
+    #[test]
+    fn strings_3() {
+        run_case("tests/test_cases/run/Standard Library/Strings - 3");
+    }
+
+    #[test]
+    fn strings_4() {
+        run_case("tests/test_cases/run/Standard Library/Strings - 4");
+    }
+
+    #[test]
+    fn math_1() {
+        run_case("tests/test_cases/run/Standard Library/Math - 1");
+    }
+
+    #[test]
+    fn math_2() {
+        run_case("tests/test_cases/run/Standard Library/Math - 2");
+    }
+
+    #[test]
+    fn math_3() {
+        run_case("tests/test_cases/run/Standard Library/Math - 3");
+    }
+
+    #[test]
+    fn math_4() {
+        run_case("tests/test_cases/run/Standard Library/Math - 4");
+    }
+
+    #[test]
+    fn math_5() {
+        run_case("tests/test_cases/run/Standard Library/Math - 5");
+    }
+
+    #[test]
+    fn math_6() {
+        run_case("tests/test_cases/run/Standard Library/Math - 6");
+    }
+
+    #[test]
+    fn random_1() {
+        run_case("tests/test_cases/run/Standard Library/Random - 1");
+    }
+
+    #[test]
+    fn random_2() {
+        run_case("tests/test_cases/run/Standard Library/Random - 2");
+    }
+
+    #[test]
+    fn random_3() {
+        run_case("tests/test_cases/run/Standard Library/Random - 3");
+    }
+
+    #[test]
+    fn random_4() {
+        run_case("tests/test_cases/run/Standard Library/Random - 4");
+    }
+
+    #[test]
+    fn random_5() {
+        run_case("tests/test_cases/run/Standard Library/Random - 5");
+    }
+
+    #[test]
+    fn random_6() {
+        run_case("tests/test_cases/run/Standard Library/Random - 6");
+    }
+
+    #[test]
+    fn console_1() {
+        run_case("tests/test_cases/run/Standard Library/Console - 1");
+    }
+
+    #[test]
+    fn files_1() {
+        run_case("tests/test_cases/run/Standard Library/Files - 1");
+    }
+
+    #[test]
+    fn files_2() {
+        run_case("tests/test_cases/run/Standard Library/Files - 2");
+    }
+
+    #[test]
+    fn files_3() {
+        run_case("tests/test_cases/run/Standard Library/Files - 3");
+    }
+
+    #[test]
+    fn json_1() {
+        run_case("tests/test_cases/run/Standard Library/Json - 1");
+    }
+
+    #[test]
+    fn json_2() {
+        run_case("tests/test_cases/run/Standard Library/Json - 2");
+    }
+
+    #[test]
+    fn json_3() {
+        run_case("tests/test_cases/run/Standard Library/Json - 3");
+    }
+
+    #[test]
+    fn json_4() {
+        run_case("tests/test_cases/run/Standard Library/Json - 4");
+    }
+
+    #[test]
+    fn json_5() {
+        run_case("tests/test_cases/run/Standard Library/Json - 5");
+    }
+
+    #[test]
+    fn json_6() {
+        run_case("tests/test_cases/run/Standard Library/Json - 6");
+    }
+
+    #[test]
+    fn scripts_1() {
+        run_case("tests/test_cases/run/Standard Library/Scripts - 1");
+    }
+
+    #[test]
+    fn scripts_2() {
+        run_case("tests/test_cases/run/Standard Library/Scripts - 2");
+    }
+
+    #[test]
+    fn scripts_3() {
+        run_case("tests/test_cases/run/Standard Library/Scripts - 3");
+    }
+
+    #[test]
+    fn scripts_4() {
+        run_case("tests/test_cases/run/Standard Library/Scripts - 4");
+    }
+
+    #[test]
+    fn scripts_5() {
+        run_case("tests/test_cases/run/Standard Library/Scripts - 5");
+    }
+}
@@ -203,4 +203,64 @@ mod statements_state {
     fn scopes_4() {
         run_case("tests/test_cases/run/Statements & State/Scopes - 4");
     }
+
+    #[test]
+    fn compound_assignment_1() {
+        run_case("tests/test_cases/run/Statements & State/Compound Assignment - 1");
+    }
+
+    #[test]
+    fn compound_assignment_2() {
+        run_case("tests/test_cases/run/Statements & State/Compound Assignment - 2");
+    }
+
+    #[test]
+    fn compound_assignment_3() {
+        run_case("tests/test_cases/run/Statements & State/Compound Assignment - 3");
+    }
+
+    #[test]
+    fn constants_1() {
+        run_case("tests/test_cases/run/Statements & State/Constants - 1");
+    }
+
+    #[test]
+    fn constants_2() {
+        run_case("tests/test_cases/run/Statements & State/Constants - 2");
+    }
+
+    #[test]
+    fn constants_3() {
+        run_case("tests/test_cases/run/Statements & State/Constants - 3");
+    }
+
+    #[test]
+    fn constants_4() {
+        run_case("tests/test_cases/run/Statements & State/Constants - 4");
+    }
+
+    #[test]
+    fn constants_5() {
+        run_case("tests/test_cases/run/Statements & State/Constants - 5");
+    }
+
+    #[test]
+    fn constants_6() {
+        run_case("tests/test_cases/run/Statements & State/Constants - 6");
+    }
+
+    #[test]
+    fn constants_7() {
+        run_case("tests/test_cases/run/Statements & State/Constants - 7");
+    }
+
+    #[test]
+    fn constants_8() {
+        run_case("tests/test_cases/run/Statements & State/Constants - 8");
+    }
+
+    #[test]
+    fn constants_9() {
+        run_case("tests/test_cases/run/Statements & State/Constants - 9");
+    }
 }